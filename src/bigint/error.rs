@@ -0,0 +1,46 @@
+use std::{
+  error::Error,
+  fmt::{Display, Formatter, Result as FmtResult},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BigIntError {
+  ZeroModulus,
+  NegativeModulus,
+  EvenModulus,
+  NegativeExponent,
+  NegativeRadicand,
+  ZeroRootDegree,
+  NotInvertible,
+  NegativeFactorial,
+  NegativeIndex,
+}
+
+impl Display for BigIntError {
+  #[inline(always)]
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::ZeroModulus => write!(formatter, "modulus must be nonzero"),
+      Self::NegativeModulus => write!(formatter, "modulus must be positive"),
+      Self::EvenModulus => write!(formatter, "modulus must be odd"),
+      Self::NegativeExponent => {
+        write!(formatter, "exponent must be nonnegative")
+      }
+      Self::NegativeRadicand => {
+        write!(formatter, "even root of a negative number")
+      }
+      Self::ZeroRootDegree => write!(formatter, "root degree must be nonzero"),
+      Self::NotInvertible => {
+        write!(formatter, "value is not invertible modulo the modulus")
+      }
+      Self::NegativeFactorial => {
+        write!(formatter, "factorial of a negative number")
+      }
+      Self::NegativeIndex => {
+        write!(formatter, "sequence index is negative")
+      }
+    }
+  }
+}
+
+impl Error for BigIntError {}
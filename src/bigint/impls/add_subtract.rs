@@ -10,27 +10,35 @@ use crate::{
 impl BigInt {
   #[inline(always)]
   fn digital_add_assign(&mut self, other: &[u32]) {
-    self.sign = Sign::Positive;
-    digital_add_in_place(&mut self.digits, other, DigitalWrap::Max);
+    if self.sign.is_negative() {
+      let (difference, diff_sign) =
+        digital_subtract(&self.digits, other, DigitalWrap::Max);
+
+      self.digits = difference;
+      self.sign = diff_sign.negated();
+    } else {
+      self.sign = Sign::Positive;
+      digital_add_in_place(&mut self.digits, other, DigitalWrap::Max);
+    }
   }
 
   #[inline(always)]
   fn digital_subtract_assign(&mut self, other: &[u32]) {
-    let (difference, diff_sign) =
-      digital_subtract(&self.digits, other, DigitalWrap::Max);
+    if self.sign.is_negative() {
+      digital_add_in_place(&mut self.digits, other, DigitalWrap::Max);
+    } else {
+      let (difference, diff_sign) =
+        digital_subtract(&self.digits, other, DigitalWrap::Max);
 
-    self.digits = difference;
-    if diff_sign.is_negative() {
-      self.sign = self.sign.negated();
-    } else if diff_sign.is_zero() {
-      self.sign = Sign::Zero;
-    };
+      self.digits = difference;
+      self.sign = diff_sign;
+    }
   }
 
   #[inline(always)]
   fn digital_add(&self, other: &[u32]) -> Self {
     Self {
-      sign: Sign::Positive,
+      sign: self.sign,
       digits: digital_add(&self.digits, other, DigitalWrap::Max),
     }
   }
@@ -84,11 +92,11 @@ impl Add<u32> for &BigInt {
 
   #[inline(always)]
   fn add(self, rhs: u32) -> Self::Output {
-    if rhs == 0 {
-      self.clone()
-    } else {
-      self.digital_add(&[rhs])
-    }
+    let mut result = self.clone();
+
+    result += rhs;
+
+    result
   }
 }
 
@@ -109,7 +117,11 @@ impl Sub for &BigInt {
   fn sub(self, rhs: Self) -> Self::Output {
     match (self.sign, rhs.sign) {
       (_, Sign::Zero) => self.to_owned(),
-      (Sign::Zero, _) => rhs.to_owned(),
+      (Sign::Zero, _) => {
+        let mut result = rhs.to_owned();
+        result.negate();
+        result
+      }
       (Sign::Positive, Sign::Positive) | (Sign::Negative, Sign::Negative) => {
         self.digital_subtract(&rhs.digits)
       }
@@ -125,7 +137,11 @@ impl Sub<u32> for &BigInt {
 
   #[inline(always)]
   fn sub(self, rhs: u32) -> Self::Output {
-    self.digital_subtract(&[rhs])
+    let mut result = self.clone();
+
+    result -= rhs;
+
+    result
   }
 }
 
@@ -0,0 +1,77 @@
+use crate::{
+  bigint::{BigInt, BigIntError},
+  utils::{BarrettReducer, Sign},
+};
+
+impl BarrettReducer {
+  /// Fails if `modulus` is zero. The sign of `modulus` is ignored.
+  #[inline]
+  pub fn new(modulus: &BigInt) -> Result<Self, BigIntError> {
+    if modulus.is_zero() {
+      return Err(BigIntError::ZeroModulus);
+    }
+
+    let base_power = &BigInt::one() << (64 * modulus.magnitude());
+    let (reciprocal, _) =
+      BigInt::divide_digits(&base_power.digits, &modulus.digits);
+
+    Ok(Self::with_reciprocal(
+      modulus.digits.clone(),
+      reciprocal,
+      BigInt::multiply_crossovers(),
+    ))
+  }
+
+  #[inline(always)]
+  pub fn modulus(&self) -> BigInt {
+    BigInt::from_parts(Sign::Positive, self.modulus_digits().to_vec())
+  }
+
+  /// Returns `value mod m` in `[0, m)`, including for negative values.
+  #[inline]
+  pub fn reduce(&self, value: &BigInt) -> BigInt {
+    let remainder =
+      BigInt::from_parts(Sign::Positive, self.reduce_digits(&value.digits));
+
+    match value.sign.is_negative() && !remainder.is_zero() {
+      true => &self.modulus() - &remainder,
+      false => remainder,
+    }
+  }
+
+  #[inline(always)]
+  pub fn mul_mod(&self, lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    self.reduce(&(lhs * rhs))
+  }
+
+  /// Panics if `exponent` is negative.
+  #[inline]
+  pub fn pow_mod(&self, base: &BigInt, exponent: &BigInt) -> BigInt {
+    if exponent.sign.is_negative() {
+      panic!("Attempt to raise to a negative power");
+    }
+
+    let one = self.reduce(&BigInt::one());
+    if exponent.is_zero() {
+      return one;
+    }
+
+    BigInt::sliding_window_pow(
+      self.reduce(base),
+      exponent,
+      one,
+      |lhs, rhs| {
+        BigInt::from_parts(
+          Sign::Positive,
+          self.mul_mod_digits(&lhs.digits, &rhs.digits),
+        )
+      },
+      |value| {
+        BigInt::from_parts(
+          Sign::Positive,
+          self.reduce_digits(&BigInt::square_digits(&value.digits)),
+        )
+      },
+    )
+  }
+}
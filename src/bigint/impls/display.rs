@@ -8,7 +8,7 @@ impl Display for BigInt {
     if self.magnitude() == 1 {
       write!(formatter, "{}", self.digits[0])
     } else {
-      let mut cloned = self.clone();
+      let mut cloned = self.abs();
 
       let mut result = vec![];
 
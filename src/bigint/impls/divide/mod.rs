@@ -1,13 +1,14 @@
-use std::{
-  cmp::Ordering,
-  ops::{Div, DivAssign, Rem},
-};
+mod newton;
+mod recursive;
+mod rem;
+
+use std::ops::{Div, DivAssign, Rem};
 
 use crate::{
   bigint::BigInt,
   utils::{
-    digital_cmp, digital_scalar_divide_in_place_u32, digital_scalar_rem_u32,
-    Sign,
+    digital_divide_with_rem_u32, digital_scalar_divide_in_place_u32,
+    digital_scalar_rem_u32, Sign,
   },
 };
 
@@ -18,16 +19,35 @@ impl Div for &BigInt {
   fn div(self, rhs: Self) -> Self::Output {
     match (self.sign, rhs.sign) {
       (_, Sign::Zero) => panic!("Attempt to divide by zero"),
-      (Sign::Zero, _) => BigInt::zero(),
-      _ => match self.cmp_magnitude(rhs) {
-        Ordering::Greater => BigInt::zero(),
-        _ => match digital_cmp(&self.digits, &rhs.digits) {
-          Ordering::Less => {
-            todo!("Full division not yet implmemented");
-          }
-          _ => BigInt::zero(),
-        },
-      },
+      _ => self.div_rem(rhs).0,
+    }
+  }
+}
+
+impl DivAssign<&BigInt> for BigInt {
+  #[inline(always)]
+  fn div_assign(&mut self, rhs: &BigInt) {
+    *self = &*self / rhs;
+  }
+}
+
+impl BigInt {
+  #[inline]
+  pub(crate) fn divide_digits(
+    lhs: &[u32],
+    rhs: &[u32],
+  ) -> (Vec<u32>, Vec<u32>) {
+    let quotient_magnitude = (lhs.len() + 1).saturating_sub(rhs.len());
+    let crossover = Self::burnikel_ziegler_crossover();
+
+    if rhs.len() >= Self::newton_crossover()
+      && quotient_magnitude >= Self::newton_crossover()
+    {
+      Self::newton_divide_digits(lhs, rhs)
+    } else if rhs.len() >= crossover && quotient_magnitude > crossover {
+      Self::recursive_divide_digits(lhs, rhs, crossover)
+    } else {
+      digital_divide_with_rem_u32(lhs, rhs)
     }
   }
 }
@@ -56,11 +76,18 @@ impl Div<u32> for &BigInt {
   }
 }
 
+// Truncated like `BigInt % BigInt` and `i64 % i64`, so a nonzero remainder
+// takes the sign of the dividend.
 impl Rem<u32> for &BigInt {
-  type Output = u32;
+  type Output = i64;
 
   #[inline(always)]
   fn rem(self, rhs: u32) -> Self::Output {
-    digital_scalar_rem_u32(&self.digits, rhs)
+    let remainder = digital_scalar_rem_u32(&self.digits, rhs) as i64;
+
+    match self.sign {
+      Sign::Negative => -remainder,
+      _ => remainder,
+    }
   }
 }
@@ -0,0 +1,149 @@
+use crate::{
+  bigint::BigInt,
+  utils::{digital_shift_left_u32, digital_shift_right_u32, Sign},
+};
+
+impl BigInt {
+  /// Fixed-point inverse of `self` with `precision_limbs` fractional digits:
+  /// `floor(2^(32 * precision_limbs) / |self|)`, carrying the sign of `self`.
+  #[inline]
+  pub fn reciprocal(&self, precision_limbs: usize) -> Self {
+    if self.is_zero() {
+      panic!("Attempt to divide by zero");
+    }
+
+    Self::from_parts(
+      self.sign,
+      Self::reciprocal_digits(&self.digits, precision_limbs),
+    )
+  }
+
+  #[inline]
+  pub(crate) fn newton_divide_digits(
+    lhs: &[u32],
+    rhs: &[u32],
+  ) -> (Vec<u32>, Vec<u32>) {
+    let precision = lhs.len();
+    let reciprocal = Self::reciprocal_digits(rhs, precision);
+
+    Self::reciprocal_divide_digits(lhs, rhs, &reciprocal, precision)
+  }
+
+  // With `reciprocal = floor(B^p / rhs)` and `lhs < B^p`, the estimate
+  // `lhs * reciprocal / B^p` undershoots the true quotient by at most two.
+  #[inline]
+  pub(crate) fn reciprocal_divide_digits(
+    lhs: &[u32],
+    rhs: &[u32],
+    reciprocal: &[u32],
+    precision: usize,
+  ) -> (Vec<u32>, Vec<u32>) {
+    let dividend = Self::from_parts(Sign::Positive, lhs.to_vec());
+    let divisor = Self::from_parts(Sign::Positive, rhs.to_vec());
+
+    let mut quotient = Self::from_parts(
+      Sign::Positive,
+      digital_shift_right_u32(
+        &Self::multiply_digits(lhs, reciprocal),
+        32 * precision,
+      ),
+    );
+    let mut remainder = &dividend - &(&quotient * &divisor);
+
+    while remainder >= divisor {
+      quotient += 1;
+      remainder = &remainder - &divisor;
+    }
+
+    (quotient.digits, remainder.digits)
+  }
+
+  #[inline]
+  fn reciprocal_digits(digits: &[u32], precision: usize) -> Vec<u32> {
+    let magnitude = digits.len();
+
+    if precision < magnitude + Self::burnikel_ziegler_crossover() {
+      return Self::divide_digits(&base_power(precision).digits, digits).0;
+    }
+
+    // Newton's method converges fastest on a divisor with its top bit set,
+    // so invert the normalized divisor to one extra digit and shift back.
+    let shift = digits[magnitude - 1].leading_zeros() as usize;
+    let normalized = digital_shift_left_u32(digits, shift);
+    let estimate = digital_shift_right_u32(
+      &Self::newton_reciprocal_digits(&normalized, precision - magnitude + 1),
+      32 - shift,
+    );
+
+    Self::correct_reciprocal(digits, precision, estimate).digits
+  }
+
+  // Approximates `floor(B^(n + fraction) / digits)` for a normalized `n`-digit
+  // divisor to within a few units, doubling the precision of an inverse of
+  // its leading digits. Only the outermost estimate is corrected exactly.
+  #[inline]
+  fn newton_reciprocal_digits(digits: &[u32], fraction: usize) -> Vec<u32> {
+    let magnitude = digits.len();
+
+    if fraction <= Self::burnikel_ziegler_crossover() {
+      return Self::divide_digits(
+        &base_power(magnitude + fraction).digits,
+        digits,
+      )
+      .0;
+    }
+
+    let half = fraction / 2 + 1;
+    let leading = usize::min(magnitude, half + 1);
+
+    let mut estimate = Self::from_parts(
+      Sign::Positive,
+      Self::newton_reciprocal_digits(&digits[magnitude - leading..], half),
+    );
+    estimate <<= 32 * (fraction - half);
+
+    let precision = magnitude + fraction;
+    let divisor = Self::from_parts(Sign::Positive, digits.to_vec());
+    let error = &base_power(precision) - &(&divisor * &estimate);
+
+    if !error.is_zero() {
+      let adjustment = Self::from_parts(
+        error.sign,
+        digital_shift_right_u32(
+          &Self::multiply_digits(&estimate.digits, &error.digits),
+          32 * precision,
+        ),
+      );
+      estimate = &estimate + &adjustment;
+    }
+
+    estimate.digits
+  }
+
+  #[inline]
+  fn correct_reciprocal(
+    digits: &[u32],
+    precision: usize,
+    estimate: Vec<u32>,
+  ) -> Self {
+    let divisor = Self::from_parts(Sign::Positive, digits.to_vec());
+    let mut estimate = Self::from_parts(Sign::Positive, estimate);
+    let mut error = &base_power(precision) - &(&divisor * &estimate);
+
+    while error.sign.is_negative() {
+      estimate -= 1;
+      error = &error + &divisor;
+    }
+    while error >= divisor {
+      estimate += 1;
+      error = &error - &divisor;
+    }
+
+    estimate
+  }
+}
+
+#[inline(always)]
+fn base_power(limbs: usize) -> BigInt {
+  &BigInt::one() << (32 * limbs)
+}
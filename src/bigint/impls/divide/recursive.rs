@@ -0,0 +1,185 @@
+use std::cmp::Ordering;
+
+use crate::{
+  bigint::BigInt,
+  utils::{
+    digital_add, digital_bit_length_u32, digital_cmp,
+    digital_divide_with_rem_u32, digital_shift_left_u32,
+    digital_shift_right_u32, digital_subtract, digital_trim_zeroes,
+    DigitalWrap, Sign,
+  },
+};
+
+impl BigInt {
+  // Burnikel and Ziegler's recursive division. The divisor is padded to a
+  // block size of `crossover * 2^k` digits so every level of the recursion
+  // splits evenly, then the dividend is consumed one block at a time.
+  #[inline]
+  pub(crate) fn recursive_divide_digits(
+    lhs: &[u32],
+    rhs: &[u32],
+    crossover: usize,
+  ) -> (Vec<u32>, Vec<u32>) {
+    let divisor_magnitude = rhs.len();
+
+    let mut block_count = 1;
+    while block_count * crossover <= divisor_magnitude {
+      block_count <<= 1;
+    }
+    let block_magnitude = divisor_magnitude.div_ceil(block_count) * block_count;
+
+    let shift = (block_magnitude - divisor_magnitude) * 32
+      + rhs[divisor_magnitude - 1].leading_zeros() as usize;
+
+    let divisor = digital_shift_left_u32(rhs, shift);
+    let dividend = digital_shift_left_u32(lhs, shift);
+
+    let dividend_blocks = usize::max(
+      2,
+      (digital_bit_length_u32(&dividend) + 1).div_ceil(block_magnitude * 32),
+    );
+
+    let mut quotient = vec![0; (dividend_blocks - 1) * block_magnitude];
+    let mut partial =
+      limb_slice(&dividend, (dividend_blocks - 2) * block_magnitude, None);
+
+    for block in (0..dividend_blocks - 1).rev() {
+      let (block_quotient, block_remainder) =
+        divide_two_by_one(&partial, &divisor, block_magnitude, crossover);
+
+      let offset = block * block_magnitude;
+      quotient[offset..offset + block_quotient.len()]
+        .copy_from_slice(&block_quotient);
+
+      partial = if block > 0 {
+        let lower = limb_slice(
+          &dividend,
+          (block - 1) * block_magnitude,
+          Some(block * block_magnitude),
+        );
+        concat_limbs(&block_remainder, &lower, block_magnitude)
+      } else {
+        block_remainder
+      };
+    }
+
+    digital_trim_zeroes(&mut quotient);
+
+    (quotient, digital_shift_right_u32(&partial, shift))
+  }
+}
+
+// Divides a number of at most `2 * magnitude` digits by a normalized divisor
+// of exactly `magnitude` digits, given that the quotient fits in `magnitude`
+// digits.
+#[inline]
+fn divide_two_by_one(
+  lhs: &[u32],
+  rhs: &[u32],
+  magnitude: usize,
+  crossover: usize,
+) -> (Vec<u32>, Vec<u32>) {
+  if magnitude % 2 == 1 || magnitude < crossover {
+    return digital_divide_with_rem_u32(lhs, rhs);
+  }
+
+  let half = magnitude / 2;
+
+  let (upper_quotient, upper_remainder) =
+    divide_three_by_two(&limb_slice(lhs, half, None), rhs, half, crossover);
+
+  let (lower_quotient, remainder) = divide_three_by_two(
+    &concat_limbs(&upper_remainder, &limb_slice(lhs, 0, Some(half)), half),
+    rhs,
+    half,
+    crossover,
+  );
+
+  (
+    concat_limbs(&upper_quotient, &lower_quotient, half),
+    remainder,
+  )
+}
+
+// Divides a number of at most `3 * magnitude` digits by a normalized divisor
+// of exactly `2 * magnitude` digits, given that the quotient fits in
+// `magnitude` digits.
+#[inline]
+fn divide_three_by_two(
+  lhs: &[u32],
+  rhs: &[u32],
+  magnitude: usize,
+  crossover: usize,
+) -> (Vec<u32>, Vec<u32>) {
+  let divisor_high = limb_slice(rhs, magnitude, None);
+  let divisor_low = limb_slice(rhs, 0, Some(magnitude));
+
+  let dividend_top = limb_slice(lhs, 2 * magnitude, None);
+  let dividend_high = limb_slice(lhs, magnitude, None);
+  let dividend_low = limb_slice(lhs, 0, Some(magnitude));
+
+  let (mut quotient, high_remainder) =
+    match digital_cmp(&dividend_top, &divisor_high) {
+      Ordering::Less => {
+        divide_two_by_one(&dividend_high, &divisor_high, magnitude, crossover)
+      }
+      _ => (
+        vec![u32::MAX; magnitude],
+        digital_subtract(
+          &digital_add(&dividend_high, &divisor_high, DigitalWrap::Max),
+          &concat_limbs(&divisor_high, &[0], magnitude),
+          DigitalWrap::Max,
+        )
+        .0,
+      ),
+    };
+
+  let estimate = concat_limbs(&high_remainder, &dividend_low, magnitude);
+  let correction = BigInt::multiply_digits(&quotient, &divisor_low);
+
+  let (mut remainder, remainder_sign) =
+    digital_subtract(&estimate, &correction, DigitalWrap::Max);
+
+  if remainder_sign.is_negative() {
+    loop {
+      quotient = digital_subtract(&quotient, &[1], DigitalWrap::Max).0;
+
+      let (difference, difference_sign) =
+        digital_subtract(&remainder, rhs, DigitalWrap::Max);
+
+      match difference_sign {
+        Sign::Positive => remainder = difference,
+        _ => {
+          remainder = difference;
+          break;
+        }
+      }
+    }
+  }
+
+  (quotient, remainder)
+}
+
+#[inline]
+fn limb_slice(digits: &[u32], start: usize, end: Option<usize>) -> Vec<u32> {
+  let end = usize::min(end.unwrap_or(digits.len()), digits.len());
+
+  let mut slice = if start < end {
+    digits[start..end].to_vec()
+  } else {
+    vec![0]
+  };
+
+  digital_trim_zeroes(&mut slice);
+  slice
+}
+
+#[inline(always)]
+fn concat_limbs(high: &[u32], low: &[u32], low_magnitude: usize) -> Vec<u32> {
+  let mut result = low.to_vec();
+  result.resize(low_magnitude, 0);
+  result.extend_from_slice(high);
+
+  digital_trim_zeroes(&mut result);
+  result
+}
@@ -0,0 +1,118 @@
+use std::ops::{Rem, RemAssign};
+
+use crate::{bigint::BigInt, utils::Sign};
+
+impl Rem for &BigInt {
+  type Output = BigInt;
+
+  #[inline(always)]
+  fn rem(self, rhs: Self) -> Self::Output {
+    self.div_rem(rhs).1
+  }
+}
+
+impl RemAssign<&BigInt> for BigInt {
+  #[inline(always)]
+  fn rem_assign(&mut self, rhs: &BigInt) {
+    *self = &*self % rhs;
+  }
+}
+
+impl BigInt {
+  /// Truncated division, matching `/` and `%` on `i64`: the quotient rounds
+  /// toward zero and the remainder takes the sign of the dividend.
+  #[inline]
+  pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+    match (self.sign, rhs.sign) {
+      (_, Sign::Zero) => panic!("Attempt to divide by zero"),
+      (Sign::Zero, _) => (Self::zero(), Self::zero()),
+      (left_sign, right_sign) => {
+        let (quotient, remainder) =
+          Self::divide_digits(&self.digits, &rhs.digits);
+
+        let quotient_sign = if left_sign == right_sign {
+          Sign::Positive
+        } else {
+          Sign::Negative
+        };
+
+        (
+          Self::from_parts(quotient_sign, quotient),
+          Self::from_parts(left_sign, remainder),
+        )
+      }
+    }
+  }
+
+  /// Floored division: the quotient rounds toward negative infinity and a
+  /// nonzero remainder takes the sign of the divisor.
+  #[inline]
+  pub fn div_rem_floor(&self, rhs: &Self) -> (Self, Self) {
+    let (mut quotient, mut remainder) = self.div_rem(rhs);
+
+    if !remainder.is_zero() && remainder.sign != rhs.sign {
+      quotient -= 1;
+      remainder = &remainder + rhs;
+    }
+
+    (quotient, remainder)
+  }
+
+  /// Ceiling division: the quotient rounds toward positive infinity and a
+  /// nonzero remainder takes the opposite sign of the divisor.
+  #[inline]
+  pub fn div_rem_ceil(&self, rhs: &Self) -> (Self, Self) {
+    let (mut quotient, mut remainder) = self.div_rem(rhs);
+
+    if !remainder.is_zero() && remainder.sign == rhs.sign {
+      quotient += 1;
+      remainder = &remainder - rhs;
+    }
+
+    (quotient, remainder)
+  }
+
+  /// Euclidean division, matching `i64::div_euclid` and `i64::rem_euclid`:
+  /// the remainder is always nonnegative and smaller than `|rhs|`.
+  #[inline]
+  pub fn div_rem_euclid(&self, rhs: &Self) -> (Self, Self) {
+    let (mut quotient, mut remainder) = self.div_rem(rhs);
+
+    if remainder.sign.is_negative() {
+      if rhs.sign.is_positive() {
+        quotient -= 1;
+        remainder = &remainder + rhs;
+      } else {
+        quotient += 1;
+        remainder = &remainder - rhs;
+      }
+    }
+
+    (quotient, remainder)
+  }
+
+  #[inline(always)]
+  pub fn div_floor(&self, rhs: &Self) -> Self {
+    self.div_rem_floor(rhs).0
+  }
+
+  #[inline(always)]
+  pub fn rem_floor(&self, rhs: &Self) -> Self {
+    self.div_rem_floor(rhs).1
+  }
+
+  #[inline(always)]
+  pub fn div_ceil(&self, rhs: &Self) -> Self {
+    self.div_rem_ceil(rhs).0
+  }
+
+  #[inline(always)]
+  pub fn div_euclid(&self, rhs: &Self) -> Self {
+    self.div_rem_euclid(rhs).0
+  }
+
+  #[inline(always)]
+  pub fn rem_euclid(&self, rhs: &Self) -> Self {
+    self.div_rem_euclid(rhs).1
+  }
+}
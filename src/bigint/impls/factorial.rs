@@ -0,0 +1,65 @@
+use crate::{
+  bigint::{BigInt, BigIntError},
+  sieve::primes_up_to,
+};
+
+// Factorials below this are computed with a single word product.
+const SMALL_FACTORIAL_LIMIT: u64 = 21;
+
+impl BigInt {
+  /// `self!` by Luschny's prime-swing algorithm, `n! = (n / 2)!^2 * swing(n)`,
+  /// where each swing number is a product of prime powers multiplied out in a
+  /// balanced product tree. Errors on negative input and panics on values of
+  /// `2^64` or more, whose factorial could not be stored.
+  #[inline]
+  pub fn fact(&self) -> Result<Self, BigIntError> {
+    if self.sign.is_negative() {
+      return Err(BigIntError::NegativeFactorial);
+    }
+    if self.magnitude() > 2 {
+      panic!("Attempt to take the factorial of a value above 2^64");
+    }
+
+    let n = self
+      .digits
+      .iter()
+      .rev()
+      .fold(0, |result, digit| (result << 32) | *digit as u64);
+    let primes: Vec<u64> = primes_up_to(n).collect();
+
+    Ok(factorial(n, &primes))
+  }
+}
+
+// `primes` holds at least every prime up to `n`.
+pub(crate) fn factorial(n: u64, primes: &[u64]) -> BigInt {
+  if n < SMALL_FACTORIAL_LIMIT {
+    return BigInt::from((2..=n).product::<u64>());
+  }
+
+  &factorial(n / 2, primes).square() * &swing(n, primes)
+}
+
+// `n! / (n / 2)!^2`, whose exponent of a prime `p` is the number of odd
+// values among `n / p^k` for `k >= 1`.
+#[inline]
+fn swing(n: u64, primes: &[u64]) -> BigInt {
+  let factors: Vec<u64> = primes
+    .iter()
+    .take_while(|prime| **prime <= n)
+    .filter_map(|&prime| {
+      let mut quotient = n;
+      let mut power = 1;
+      while quotient >= prime {
+        quotient /= prime;
+        if quotient & 1 == 1 {
+          power *= prime;
+        }
+      }
+
+      (power > 1).then_some(power)
+    })
+    .collect();
+
+  BigInt::product(&factors)
+}
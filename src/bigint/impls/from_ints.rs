@@ -4,18 +4,13 @@ use crate::{bigint::BigInt, utils::Sign};
 
 impl From<u64> for BigInt {
   #[inline(always)]
-  fn from(mut value: u64) -> Self {
-    let mut digits = vec![];
+  fn from(value: u64) -> Self {
+    let mut digits = vec![value as u32];
 
     if value > u32::MAX as u64 {
-      let mut half = u32::MAX as u64;
-      half <<= 32;
-      digits.push((value - half) as u32);
-      value >>= 32;
+      digits.push((value >> 32) as u32);
     }
 
-    digits.push(value as u32);
-
     Self {
       sign: match value.cmp(&0) {
         Ordering::Equal => Sign::Zero,
@@ -37,17 +32,14 @@ impl From<i64> for BigInt {
       Ordering::Less => Sign::Negative,
     };
 
-    let mut unsigned_value = value.unsigned_abs();
+    let unsigned_value = value.unsigned_abs();
+
+    digits.push(unsigned_value as u32);
 
     if unsigned_value > u32::MAX as u64 {
-      let mut half = u32::MAX as u64;
-      half <<= 32;
-      digits.push((unsigned_value - half) as u32);
-      unsigned_value >>= 32;
+      digits.push((unsigned_value >> 32) as u32);
     }
 
-    digits.push(unsigned_value as u32);
-
     Self { sign, digits }
   }
 }
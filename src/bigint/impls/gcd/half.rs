@@ -0,0 +1,241 @@
+use std::mem;
+
+use super::{lehmer_cofactors, Cofactors};
+use crate::{bigint::BigInt, utils::Sign};
+
+// A unimodular matrix `M` with `(a, b) = M * (a', b')`, taking a pair to a
+// later, smaller pair with the same greatest common divisor.
+pub(super) struct Matrix {
+  entries: [BigInt; 4],
+  negative_determinant: bool,
+}
+
+impl Matrix {
+  #[inline(always)]
+  fn identity() -> Self {
+    Self {
+      entries: [BigInt::one(), BigInt::zero(), BigInt::zero(), BigInt::one()],
+      negative_determinant: false,
+    }
+  }
+
+  #[inline(always)]
+  fn from_quotient(quotient: BigInt) -> Self {
+    Self {
+      entries: [quotient, BigInt::one(), BigInt::one(), BigInt::zero()],
+      negative_determinant: true,
+    }
+  }
+
+  // Lehmer cofactors map `(a, b)` forward to `(a', b')`, so the matrix is
+  // their inverse.
+  #[inline]
+  fn from_cofactors(cofactors: Cofactors) -> Self {
+    let (a, b, c, d) = cofactors;
+    let negative_determinant =
+      (a as i128 * d as i128) < (b as i128 * c as i128);
+
+    let mut entries = [
+      BigInt::from(d),
+      BigInt::from(-b),
+      BigInt::from(-c),
+      BigInt::from(a),
+    ];
+    if negative_determinant {
+      entries.iter_mut().for_each(BigInt::negate);
+    }
+
+    Self {
+      entries,
+      negative_determinant,
+    }
+  }
+
+  #[inline(always)]
+  pub(super) fn is_identity(&self) -> bool {
+    self.entries[1].is_zero()
+      && self.entries[2].is_zero()
+      && self.entries[0] == BigInt::one()
+      && self.entries[3] == BigInt::one()
+  }
+
+  #[inline]
+  fn multiply(&self, rhs: &Self) -> Self {
+    let [a, b, c, d] = &self.entries;
+    let [e, f, g, h] = &rhs.entries;
+
+    Self {
+      entries: [
+        &(a * e) + &(b * g),
+        &(a * f) + &(b * h),
+        &(c * e) + &(d * g),
+        &(c * f) + &(d * h),
+      ],
+      negative_determinant: self.negative_determinant
+        ^ rhs.negative_determinant,
+    }
+  }
+
+  #[inline]
+  pub(super) fn apply_inverse(
+    &self,
+    larger: &BigInt,
+    smaller: &BigInt,
+  ) -> (BigInt, BigInt) {
+    let [a, b, c, d] = &self.entries;
+
+    let mut next_larger = &(d * larger) - &(b * smaller);
+    let mut next_smaller = &(a * smaller) - &(c * larger);
+    if self.negative_determinant {
+      next_larger.negate();
+      next_smaller.negate();
+    }
+
+    (next_larger, next_smaller)
+  }
+
+  // `M^-1 (a, b)` given `M^-1 (a >> shift, b >> shift)`, which is what the
+  // recursion that found `M` reduced. The map is linear, so only the parts
+  // below `shift` are left to multiply.
+  #[inline]
+  fn apply_inverse_below(
+    &self,
+    (high_larger, high_smaller): (BigInt, BigInt),
+    larger: &BigInt,
+    smaller: &BigInt,
+    shift: usize,
+  ) -> (BigInt, BigInt) {
+    let (low_larger, low_smaller) =
+      self.apply_inverse(&low_bits(larger, shift), &low_bits(smaller, shift));
+
+    (
+      &(&high_larger << shift) + &low_larger,
+      &(&high_smaller << shift) + &low_smaller,
+    )
+  }
+
+  // Keeps `larger >= smaller >= 0` by flipping signs and swapping columns,
+  // which preserves both the identity `(a, b) = M * (a', b')` and the gcd.
+  #[inline]
+  fn normalize(&mut self, larger: &mut BigInt, smaller: &mut BigInt) {
+    for (idx, value) in [&mut *larger, &mut *smaller].into_iter().enumerate() {
+      if value.sign.is_negative() {
+        value.negate();
+        self.entries[idx].negate();
+        self.entries[idx + 2].negate();
+        self.negative_determinant = !self.negative_determinant;
+      }
+    }
+
+    if *larger < *smaller {
+      mem::swap(larger, smaller);
+      self.entries.swap(0, 1);
+      self.entries.swap(2, 3);
+      self.negative_determinant = !self.negative_determinant;
+    }
+  }
+}
+
+// Reduces `larger >= smaller >= 0` of `n` bits until `smaller` has about
+// `n / 2` bits, returning the transformation and the reduced pair. The
+// first recursion works on the top half of both operands, the second on
+// the top half of what remains, so the cost is dominated by the matrix
+// products rather than by individual quotient steps.
+#[inline]
+pub(super) fn half_gcd(
+  larger: &BigInt,
+  smaller: &BigInt,
+  crossover: usize,
+) -> (Matrix, BigInt, BigInt) {
+  let target = larger.bit_length().div_ceil(2);
+
+  if smaller.bit_length() <= target {
+    return (Matrix::identity(), larger.clone(), smaller.clone());
+  }
+  if larger.magnitude() < crossover {
+    return base_half_gcd(larger, smaller, target);
+  }
+
+  let (mut matrix, high_larger, high_smaller) =
+    half_gcd(&(larger >> target), &(smaller >> target), crossover);
+  let (mut larger, mut smaller) = matrix.apply_inverse_below(
+    (high_larger, high_smaller),
+    larger,
+    smaller,
+    target,
+  );
+  matrix.normalize(&mut larger, &mut smaller);
+
+  if smaller.bit_length() <= target {
+    return (matrix, larger, smaller);
+  }
+
+  let (quotient, remainder) = larger.div_rem(&smaller);
+  matrix = matrix.multiply(&Matrix::from_quotient(quotient));
+  let (larger, smaller) = (smaller, remainder);
+
+  if smaller.bit_length() <= target {
+    return (matrix, larger, smaller);
+  }
+
+  // Shifting so that the top part has twice as many bits as it must lose
+  // makes its own half-GCD stop right at `target` bits.
+  let shift = (2 * target).saturating_sub(larger.bit_length());
+  let (mut matrix, mut larger, mut smaller) = match shift {
+    0 => (matrix, larger, smaller),
+    _ => {
+      let (second, high_larger, high_smaller) =
+        half_gcd(&(&larger >> shift), &(&smaller >> shift), crossover);
+      let (larger, smaller) = second.apply_inverse_below(
+        (high_larger, high_smaller),
+        &larger,
+        &smaller,
+        shift,
+      );
+      (matrix.multiply(&second), larger, smaller)
+    }
+  };
+  matrix.normalize(&mut larger, &mut smaller);
+
+  while smaller.bit_length() > target {
+    let (quotient, remainder) = larger.div_rem(&smaller);
+    matrix = matrix.multiply(&Matrix::from_quotient(quotient));
+    (larger, smaller) = (smaller, remainder);
+  }
+
+  (matrix, larger, smaller)
+}
+
+#[inline]
+fn base_half_gcd(
+  larger: &BigInt,
+  smaller: &BigInt,
+  target: usize,
+) -> (Matrix, BigInt, BigInt) {
+  let mut matrix = Matrix::identity();
+  let (mut larger, mut smaller) = (larger.clone(), smaller.clone());
+
+  while smaller.bit_length() > target {
+    let step = match lehmer_cofactors(&larger, &smaller) {
+      Some(cofactors) => Matrix::from_cofactors(cofactors),
+      None => Matrix::from_quotient(&larger / &smaller),
+    };
+
+    (larger, smaller) = step.apply_inverse(&larger, &smaller);
+    matrix = matrix.multiply(&step);
+  }
+
+  (matrix, larger, smaller)
+}
+
+// `value mod 2^bits` for a nonnegative `value`.
+#[inline]
+fn low_bits(value: &BigInt, bits: usize) -> BigInt {
+  let magnitude = usize::min(bits.div_ceil(32), value.digits.len());
+  let mut digits = value.digits[..magnitude].to_vec();
+  if magnitude == bits.div_ceil(32) && !bits.is_multiple_of(32) {
+    digits[magnitude - 1] &= (1 << (bits % 32)) - 1;
+  }
+
+  BigInt::from_parts(Sign::Positive, digits)
+}
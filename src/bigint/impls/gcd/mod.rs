@@ -0,0 +1,245 @@
+pub mod half;
+
+use std::mem;
+
+use crate::bigint::{BigInt, BigIntError};
+use half::{half_gcd, Matrix};
+
+// Cofactors `(A, B, C, D)` such that `(A * a + B * b, C * a + D * b)` are
+// two consecutive remainders of the Euclidean sequence starting at `(a, b)`.
+type Cofactors = (i64, i64, i64, i64);
+
+// Sizes in limbs: `half_gcd` is where the Euclidean algorithm switches from
+// Lehmer steps to half-GCD reductions, and `base` is where the half-GCD
+// recursion bottoms out in Lehmer steps of its own.
+#[derive(Clone, Copy)]
+pub(crate) struct GcdCrossovers {
+  pub(crate) half_gcd: usize,
+  pub(crate) base: usize,
+}
+
+impl BigInt {
+  /// The greatest common divisor, always nonnegative. `gcd(0, 0)` is zero.
+  #[inline]
+  pub fn gcd(&self, other: &Self) -> Self {
+    self.gcd_with_crossovers(other, Self::gcd_crossovers())
+  }
+
+  #[inline]
+  pub(crate) fn gcd_with_crossovers(
+    &self,
+    other: &Self,
+    crossovers: GcdCrossovers,
+  ) -> Self {
+    let (mut larger, mut smaller) = ordered_magnitudes(self, other);
+
+    while !smaller.is_zero() {
+      (larger, smaller) = EuclidStep::new(&larger, &smaller, crossovers)
+        .remainders(&larger, &smaller);
+    }
+
+    larger
+  }
+
+  #[inline(always)]
+  pub(crate) fn gcd_crossovers() -> GcdCrossovers {
+    GcdCrossovers {
+      half_gcd: Self::half_gcd_crossover(),
+      base: Self::half_gcd_base_crossover(),
+    }
+  }
+
+  /// The least common multiple, always nonnegative. Zero if either side is.
+  #[inline(always)]
+  pub fn lcm(&self, other: &Self) -> Self {
+    if self.is_zero() || other.is_zero() {
+      return Self::zero();
+    }
+
+    (&(self / &self.gcd(other)) * other).abs()
+  }
+
+  /// Returns `(g, x, y)` with `g = gcd(self, other) >= 0` and
+  /// `self * x + other * y = g`, whatever the signs of the inputs.
+  #[inline]
+  pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+    let swapped = self.abs() < other.abs();
+    let (mut larger, mut smaller) = ordered_magnitudes(self, other);
+
+    // Coefficients of `larger` and `smaller` against the larger input.
+    let mut larger_coefficient = Self::one();
+    let mut smaller_coefficient = Self::zero();
+
+    while !smaller.is_zero() {
+      let step = EuclidStep::new(&larger, &smaller, Self::gcd_crossovers());
+
+      (larger_coefficient, smaller_coefficient) =
+        step.apply(&larger_coefficient, &smaller_coefficient);
+      (larger, smaller) = step.remainders(&larger, &smaller);
+    }
+
+    let (first, second) = match swapped {
+      true => (other, self),
+      false => (self, other),
+    };
+
+    if larger.is_zero() {
+      return (larger, Self::zero(), Self::zero());
+    }
+
+    // `|first| * c + |second| * d = g` gives `d`; then fix up the signs.
+    let mut first_coefficient = larger_coefficient;
+    let mut second_coefficient = match second.is_zero() {
+      true => Self::zero(),
+      false => {
+        &(&larger - &(&first.abs() * &first_coefficient)) / &second.abs()
+      }
+    };
+
+    if first.sign.is_negative() {
+      first_coefficient.negate();
+    }
+    if second.sign.is_negative() {
+      second_coefficient.negate();
+    }
+
+    match swapped {
+      true => (larger, second_coefficient, first_coefficient),
+      false => (larger, first_coefficient, second_coefficient),
+    }
+  }
+
+  /// Returns the inverse of `self` modulo `|modulus|`, in `[0, |modulus|)`.
+  #[inline]
+  pub fn mod_inverse(&self, modulus: &Self) -> Result<Self, BigIntError> {
+    if modulus.is_zero() {
+      return Err(BigIntError::ZeroModulus);
+    }
+
+    let modulus = modulus.abs();
+    let (gcd, coefficient, _) =
+      self.rem_euclid(&modulus).extended_gcd(&modulus);
+
+    if gcd != Self::one() {
+      return Err(BigIntError::NotInvertible);
+    }
+
+    Ok(coefficient.rem_euclid(&modulus))
+  }
+}
+
+#[inline(always)]
+fn ordered_magnitudes(lhs: &BigInt, rhs: &BigInt) -> (BigInt, BigInt) {
+  let (lhs, rhs) = (lhs.abs(), rhs.abs());
+
+  match lhs < rhs {
+    true => (rhs, lhs),
+    false => (lhs, rhs),
+  }
+}
+
+// One round of the Euclidean algorithm: a half-GCD reduction for large
+// operands, a batch of steps found by Lehmer's single-precision
+// simulation, or a single full division.
+enum EuclidStep {
+  HalfGcd(Matrix, (BigInt, BigInt)),
+  Lehmer(Cofactors),
+  Division(BigInt),
+}
+
+impl EuclidStep {
+  #[inline]
+  fn new(larger: &BigInt, smaller: &BigInt, crossovers: GcdCrossovers) -> Self {
+    if smaller.magnitude() >= crossovers.half_gcd {
+      let (matrix, next_larger, next_smaller) =
+        half_gcd(larger, smaller, crossovers.base);
+      if !matrix.is_identity() {
+        return Self::HalfGcd(matrix, (next_larger, next_smaller));
+      }
+    }
+
+    match lehmer_cofactors(larger, smaller) {
+      Some(cofactors) => Self::Lehmer(cofactors),
+      None => Self::Division(larger / smaller),
+    }
+  }
+
+  // The next pair of remainders after `(larger, smaller)`. Half-GCD has
+  // already produced it alongside its matrix, so only the other steps
+  // compute it here.
+  #[inline]
+  fn remainders(self, larger: &BigInt, smaller: &BigInt) -> (BigInt, BigInt) {
+    match self {
+      Self::HalfGcd(_, next) => next,
+      step => step.apply(larger, smaller),
+    }
+  }
+
+  // Applies the same transformation that takes `(larger, smaller)` to the
+  // next pair of remainders to any other pair, such as their coefficients.
+  #[inline]
+  fn apply(&self, larger: &BigInt, smaller: &BigInt) -> (BigInt, BigInt) {
+    match self {
+      Self::HalfGcd(matrix, _) => matrix.apply_inverse(larger, smaller),
+      Self::Lehmer(cofactors) => combine(larger, smaller, *cofactors),
+      Self::Division(quotient) => {
+        (smaller.clone(), larger - &(quotient * smaller))
+      }
+    }
+  }
+}
+
+#[inline]
+fn combine(
+  lhs: &BigInt,
+  rhs: &BigInt,
+  cofactors: Cofactors,
+) -> (BigInt, BigInt) {
+  let (a, b, c, d) = cofactors;
+
+  (
+    &(&BigInt::from(a) * lhs) + &(&BigInt::from(b) * rhs),
+    &(&BigInt::from(c) * lhs) + &(&BigInt::from(d) * rhs),
+  )
+}
+
+// Knuth's Algorithm L: runs Euclid on the leading 32 bits of both operands
+// while the quotients provably match those of the full operands.
+#[inline]
+fn lehmer_cofactors(larger: &BigInt, smaller: &BigInt) -> Option<Cofactors> {
+  if smaller.magnitude() < 2 {
+    return None;
+  }
+
+  let shift = larger.bit_length() - 32;
+  let mut larger_digit = leading_bits(larger, shift);
+  let mut smaller_digit = leading_bits(smaller, shift);
+
+  let (mut a, mut b, mut c, mut d) = (1, 0, 0, 1);
+
+  while smaller_digit + c != 0 && smaller_digit + d != 0 {
+    let quotient = (larger_digit + a) / (smaller_digit + c);
+    if quotient != (larger_digit + b) / (smaller_digit + d) {
+      break;
+    }
+
+    (a, c) = (c, a - quotient * c);
+    (b, d) = (d, b - quotient * d);
+    larger_digit -= quotient * smaller_digit;
+    mem::swap(&mut larger_digit, &mut smaller_digit);
+  }
+
+  match b {
+    0 => None,
+    _ => Some((a, b, c, d)),
+  }
+}
+
+#[inline(always)]
+fn leading_bits(value: &BigInt, shift: usize) -> i64 {
+  let idx = shift / 32;
+  let low = value.digits.get(idx).copied().unwrap_or(0) as u64;
+  let high = value.digits.get(idx + 1).copied().unwrap_or(0) as u64;
+
+  (((high << 32) | low) >> (shift % 32)) as u32 as i64
+}
@@ -1,8 +1,17 @@
 pub mod add_subtract;
+pub mod barrett;
 pub mod default;
 pub mod display;
 pub mod divide;
+pub mod factorial;
 pub mod from_ints;
+pub mod gcd;
+pub mod modpow;
 pub mod multiply;
 pub mod partial_eq;
 pub mod partial_ord;
+pub mod prime;
+pub mod root;
+pub mod sequence;
+pub mod shift;
+pub mod symbol;
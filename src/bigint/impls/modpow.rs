@@ -0,0 +1,113 @@
+use crate::bigint::{BigInt, BigIntError, MontgomeryContext};
+
+impl BigInt {
+  /// Computes `self^exponent mod |modulus|` by sliding-window
+  /// exponentiation. The result always lies in `[0, |modulus|)`, including
+  /// for negative bases.
+  #[inline]
+  pub fn modpow(
+    &self,
+    exponent: &BigInt,
+    modulus: &BigInt,
+  ) -> Result<Self, BigIntError> {
+    if modulus.is_zero() {
+      return Err(BigIntError::ZeroModulus);
+    }
+    if exponent.sign.is_negative() {
+      return Err(BigIntError::NegativeExponent);
+    }
+
+    let modulus = modulus.abs();
+    let one = Self::one();
+
+    if modulus == one {
+      return Ok(Self::zero());
+    }
+    if exponent.is_zero() {
+      return Ok(one);
+    }
+
+    let base = self.rem_euclid(&modulus);
+    if base.is_zero() {
+      return Ok(base);
+    }
+
+    if modulus.is_odd() {
+      let context = MontgomeryContext::new(&modulus)?;
+      let power = context.pow(&context.to_montgomery(&base), exponent);
+      return Ok(context.from_montgomery(&power));
+    }
+
+    let reduce = |value: &Self| value.rem_euclid(&modulus);
+
+    Ok(Self::sliding_window_pow(
+      base,
+      exponent,
+      one,
+      |lhs, rhs| reduce(&(lhs * rhs)),
+      |value| reduce(&value.square()),
+    ))
+  }
+
+  // Left-to-right sliding-window exponentiation over any multiplication,
+  // used for both plain and Montgomery-form modular powers.
+  #[inline]
+  pub(crate) fn sliding_window_pow(
+    base: Self,
+    exponent: &BigInt,
+    one: Self,
+    multiply: impl Fn(&Self, &Self) -> Self,
+    square: impl Fn(&Self) -> Self,
+  ) -> Self {
+    let window = window_size(exponent.bit_length());
+
+    // Odd powers `base^1, base^3, ..., base^(2^window - 1)`.
+    let base_squared = square(&base);
+    let mut odd_powers = vec![base];
+    for idx in 1..1 << (window - 1) {
+      odd_powers.push(multiply(&odd_powers[idx - 1], &base_squared));
+    }
+
+    let mut result = one;
+    let mut bit = exponent.bit_length();
+
+    while bit > 0 {
+      if !exponent.test_bit(bit - 1) {
+        result = square(&result);
+        bit -= 1;
+        continue;
+      }
+
+      // Take the longest run of at most `window` bits that ends in a set
+      // bit, so that its value indexes the table of odd powers.
+      let mut low = bit.saturating_sub(window);
+      while !exponent.test_bit(low) {
+        low += 1;
+      }
+
+      let mut value = 0;
+      for idx in (low..bit).rev() {
+        result = square(&result);
+        value = (value << 1) | exponent.test_bit(idx) as usize;
+      }
+
+      result = multiply(&result, &odd_powers[value >> 1]);
+      bit = low;
+    }
+
+    result
+  }
+}
+
+#[inline]
+fn window_size(exponent_bits: usize) -> usize {
+  match exponent_bits {
+    0..=7 => 1,
+    8..=23 => 2,
+    24..=79 => 3,
+    80..=239 => 4,
+    240..=671 => 5,
+    672..=1791 => 6,
+    _ => 7,
+  }
+}
@@ -2,7 +2,7 @@ use std::ops::{Mul, MulAssign};
 
 use crate::{
   bigint::BigInt,
-  utils::{digital_multiply_u32, karatsuba_mul},
+  utils::{digital_multiply_dispatch_u32, MultiplyCrossovers},
 };
 
 impl Mul for &BigInt {
@@ -24,35 +24,34 @@ impl MulAssign<&BigInt> for BigInt {
     if rhs.is_zero() {
       return self.zero_out();
     }
+    if self.is_zero() {
+      return;
+    }
 
-    self.digits = if self.magnitude() > Self::karatsuba_crossover()
-      && rhs.magnitude() > Self::karatsuba_crossover()
-    {
-      karatsuba_mul(&self.digits, &rhs.digits, Self::karatsuba_crossover())
-    } else {
-      digital_multiply_u32(&self.digits, &rhs.digits)
-    };
-
-    if rhs.sign.is_negative() {
-      self.sign = self.sign.negated();
+    if *self == *rhs {
+      return self.square_assign();
     }
+
+    self.digits = Self::multiply_digits(&self.digits, &rhs.digits);
+    self.trim_zeroes();
+
+    self.sign = self.sign * rhs.sign;
   }
 }
 
 impl BigInt {
   #[inline(always)]
-  pub fn pow(&self, rhs: u64) -> Self {
-    let mut result = self.clone();
-
-    result.pow_assign(rhs);
-
-    result
+  pub(crate) fn multiply_crossovers() -> MultiplyCrossovers {
+    MultiplyCrossovers {
+      karatsuba: Self::karatsuba_crossover(),
+      toom_3: Self::toom_3_crossover(),
+      toom_4: Self::toom_4_crossover(),
+      ntt: Self::ntt_crossover(),
+    }
   }
 
   #[inline(always)]
-  pub fn pow_assign(&mut self, rhs: u64) {
-    for _ in 1..rhs {
-      *self *= &rhs.into();
-    }
+  pub(crate) fn multiply_digits(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    digital_multiply_dispatch_u32(lhs, rhs, Self::multiply_crossovers())
   }
 }
@@ -1,2 +1,5 @@
 mod digital;
+mod pow;
+mod product;
 mod scalar;
+mod square;
@@ -0,0 +1,87 @@
+use crate::{bigint::BigInt, utils::Sign};
+
+impl BigInt {
+  #[inline(always)]
+  pub fn pow(&self, rhs: u64) -> Self {
+    self.pow_digits(&[rhs as u32, (rhs >> 32) as u32])
+  }
+
+  #[inline(always)]
+  pub fn pow_assign(&mut self, rhs: u64) {
+    *self = self.pow(rhs);
+  }
+
+  /// Panics if `exponent` is negative.
+  #[inline(always)]
+  pub fn pow_big(&self, exponent: &BigInt) -> Self {
+    if exponent.sign == Sign::Negative {
+      panic!("Attempt to raise to a negative power");
+    }
+
+    self.pow_digits(&exponent.digits)
+  }
+
+  #[inline(always)]
+  pub fn pow_big_assign(&mut self, exponent: &BigInt) {
+    *self = self.pow_big(exponent);
+  }
+
+  /// Returns `None` instead of computing a power whose bit length would
+  /// exceed `max_bits`.
+  #[inline]
+  pub fn checked_pow(&self, rhs: u64, max_bits: usize) -> Option<Self> {
+    let base_bits = self.bit_length() as u64;
+
+    if base_bits <= 1 || rhs == 0 {
+      return Some(self.pow(rhs));
+    }
+
+    // A power of a `b`-bit base has between `(b - 1) * e + 1` and `b * e`
+    // bits, so only the band in between needs the power to be computed.
+    let lower_bound = (base_bits - 1).saturating_mul(rhs).saturating_add(1);
+    if lower_bound > max_bits as u64 {
+      return None;
+    }
+
+    let result = self.pow(rhs);
+    match result.bit_length() <= max_bits {
+      true => Some(result),
+      false => None,
+    }
+  }
+
+  // Left-to-right binary exponentiation over the limbs of the exponent.
+  #[inline]
+  fn pow_digits(&self, exponent: &[u32]) -> Self {
+    if exponent.iter().all(|digit| *digit == 0) {
+      return Self::one();
+    }
+    if self.is_zero() {
+      return Self::zero();
+    }
+    if self.digits == [1] {
+      return match self.sign == Sign::Negative && exponent[0] & 1 == 1 {
+        true => Self::negative_one(),
+        false => Self::one(),
+      };
+    }
+
+    let mut result = Self::one();
+    let mut started = false;
+
+    for digit in exponent.iter().rev() {
+      for bit in (0..32).rev() {
+        if started {
+          result.square_assign();
+        }
+
+        if (digit >> bit) & 1 == 1 {
+          result *= self;
+          started = true;
+        }
+      }
+    }
+
+    result
+  }
+}
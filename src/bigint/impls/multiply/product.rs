@@ -0,0 +1,24 @@
+use crate::bigint::BigInt;
+
+// Below this many factors a product is accumulated one word at a time.
+const PRODUCT_LEAF: usize = 16;
+
+impl BigInt {
+  /// The product of `values` by a balanced product tree, so the largest
+  /// multiplications have operands of equal size and reach the fast
+  /// multiplication algorithms. The empty product is one.
+  #[inline]
+  pub fn product(values: &[u64]) -> Self {
+    if values.len() <= PRODUCT_LEAF {
+      return values.iter().fold(Self::one(), |product, value| {
+        match u32::try_from(*value) {
+          Ok(value) => &product * value,
+          Err(_) => &product * &Self::from(*value),
+        }
+      });
+    }
+
+    let (low, high) = values.split_at(values.len() / 2);
+    &Self::product(low) * &Self::product(high)
+  }
+}
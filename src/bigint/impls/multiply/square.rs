@@ -0,0 +1,32 @@
+use crate::{
+  bigint::BigInt,
+  utils::{digital_square_dispatch_u32, Sign},
+};
+
+impl BigInt {
+  #[inline(always)]
+  pub(crate) fn square_digits(digits: &[u32]) -> Vec<u32> {
+    digital_square_dispatch_u32(digits, Self::multiply_crossovers())
+  }
+
+  #[inline(always)]
+  pub fn square(&self) -> Self {
+    let mut result = self.clone();
+
+    result.square_assign();
+
+    result
+  }
+
+  #[inline(always)]
+  pub fn square_assign(&mut self) {
+    if self.is_zero() {
+      return;
+    }
+
+    self.digits = Self::square_digits(&self.digits);
+    self.trim_zeroes();
+
+    self.sign = Sign::Positive;
+  }
+}
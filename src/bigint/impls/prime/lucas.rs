@@ -0,0 +1,63 @@
+use crate::bigint::BigInt;
+
+// The strong Lucas test with Selfridge's parameters: `D` is the first of
+// `5, -7, 9, -11, ...` with `(D / n) = -1`, `P = 1` and `Q = (1 - D) / 4`.
+// Writing `n + 1 = odd * 2^twos`, a prime passes if `U_odd = 0` or
+// `V_(odd * 2^r) = 0 (mod n)` for some `r < twos`. Expects an odd `n` with
+// no small factors.
+#[inline]
+pub(super) fn is_strong_lucas_probable_prime(n: &BigInt) -> bool {
+  // No `D` has `(D / n) = -1` when `n` is a square.
+  let root = n.sqrt().expect("candidates are positive");
+  if root.square() == *n {
+    return false;
+  }
+
+  let mut discriminant = 5i64;
+  loop {
+    match BigInt::from(discriminant).jacobi(n) {
+      Ok(-1) => break,
+      // `|D|` is far below `n`, so `n` has a proper factor in common with it.
+      Ok(0) => return false,
+      _ => {
+        discriminant = match discriminant > 0 {
+          true => -(discriminant + 2),
+          false => -discriminant + 2,
+        }
+      }
+    }
+  }
+
+  let reduce = |value: &BigInt| value.rem_euclid(n);
+  // Division by two modulo odd `n`, for a value already in `[0, n)`.
+  let halve = |value: BigInt| match value.is_odd() {
+    true => &(&value + n) >> 1,
+    false => &value >> 1,
+  };
+
+  let d = BigInt::from(discriminant);
+  let q = BigInt::from((1 - discriminant) / 4);
+
+  let n_plus_one = n + 1;
+  let twos = n_plus_one.trailing_zeros();
+  let odd = &n_plus_one >> twos;
+
+  let (u, mut v) = BigInt::lucas_sequence(&BigInt::one(), &q, &odd, n)
+    .expect("candidates are positive");
+  // `V_k^2 - D U_k^2 = 4 Q^k` recovers `Q^odd` without another ladder.
+  let mut q_power = halve(halve(reduce(&(&v.square() - &(&d * &u.square())))));
+
+  if u.is_zero() || v.is_zero() {
+    return true;
+  }
+
+  for _ in 1..twos {
+    v = reduce(&(&v.square() - &(&q_power << 1)));
+    if v.is_zero() {
+      return true;
+    }
+    q_power = reduce(&q_power.square());
+  }
+
+  false
+}
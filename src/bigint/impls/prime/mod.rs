@@ -0,0 +1,147 @@
+mod lucas;
+mod search;
+
+use crate::{
+  bigint::{BigInt, MontgomeryContext},
+  sieve::SMALL_PRIMES,
+  utils::{Sign, SplitMix64},
+};
+
+pub use search::PrimesFrom;
+
+// Bases that make Miller-Rabin exact below `3.3 * 10^24`, so in particular
+// for every input that fits in 64 bits.
+const DETERMINISTIC_WITNESSES: [u32; 12] =
+  [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+impl BigInt {
+  /// Trial division, a strong base-2 test and then `rounds` Miller-Rabin
+  /// tests with pseudo-random bases. Inputs below `2^64` use a fixed witness
+  /// set and are always classified correctly. Above that, a composite passes
+  /// with probability at most `4^-rounds`, and `rounds = 0` still runs the
+  /// base-2 test.
+  #[inline]
+  pub fn is_probable_prime(&self, rounds: u32) -> bool {
+    if let Some(result) = trial_division(self) {
+      return result;
+    }
+
+    let test = MillerRabin::new(self);
+    if self.bit_length() <= 64 {
+      return test.passes_deterministic();
+    }
+
+    // Seeded from the input so the answer for a given value never changes.
+    let mut rng =
+      SplitMix64::new(self.digits[0] as u64 ^ self.bit_length() as u64);
+    let range = self - 3;
+
+    test.passes(&BigInt::from(2u32))
+      && (0..rounds).all(|_| {
+        let digits = (0..self.magnitude()).map(|_| rng.next_u32()).collect();
+        let base =
+          &BigInt::from_parts(Sign::Positive, digits).rem_euclid(&range) + 2;
+
+        test.passes(&base)
+      })
+  }
+
+  /// The Baillie-PSW test: trial division, a strong base-2 test and a strong
+  /// Lucas test. No composite is known to pass it, and none exists below
+  /// `2^64`.
+  #[inline]
+  pub fn is_prime_bpsw(&self) -> bool {
+    if let Some(result) = trial_division(self) {
+      return result;
+    }
+
+    let test = MillerRabin::new(self);
+    if self.bit_length() <= 64 {
+      return test.passes_deterministic();
+    }
+
+    test.passes(&BigInt::from(2u32))
+      && lucas::is_strong_lucas_probable_prime(self)
+  }
+}
+
+// Settles inputs that are nonpositive, small, or have a small factor.
+#[inline]
+fn trial_division(value: &BigInt) -> Option<bool> {
+  if !value.sign.is_positive() || value.digits == [1] {
+    return Some(false);
+  }
+
+  for prime in SMALL_PRIMES {
+    if value.digits == [prime] {
+      return Some(true);
+    }
+    if value % prime == 0 {
+      return Some(false);
+    }
+  }
+
+  let limit = SMALL_PRIMES[SMALL_PRIMES.len() - 1] + 1;
+  match value.magnitude() == 1 && value.digits[0] < limit * limit {
+    true => Some(true),
+    false => None,
+  }
+}
+
+// Strong probable-prime tests against a fixed odd modulus `n`, with
+// `n - 1 = odd * 2^twos` and everything kept in Montgomery form.
+struct MillerRabin {
+  context: MontgomeryContext,
+  odd: BigInt,
+  twos: usize,
+  minus_one: BigInt,
+}
+
+impl MillerRabin {
+  #[inline]
+  fn new(modulus: &BigInt) -> Self {
+    let context = MontgomeryContext::new(modulus)
+      .expect("trial division leaves odd values");
+    let n_minus_one = modulus - 1;
+    let twos = n_minus_one.trailing_zeros();
+
+    Self {
+      minus_one: modulus - context.one(),
+      odd: &n_minus_one >> twos,
+      twos,
+      context,
+    }
+  }
+
+  #[inline(always)]
+  fn passes_deterministic(&self) -> bool {
+    DETERMINISTIC_WITNESSES
+      .iter()
+      .all(|witness| self.passes(&BigInt::from(*witness)))
+  }
+
+  #[inline]
+  fn passes(&self, base: &BigInt) -> bool {
+    let one = self.context.one();
+    let mut power = self
+      .context
+      .pow(&self.context.to_montgomery(base), &self.odd);
+
+    if power == *one || power == self.minus_one {
+      return true;
+    }
+
+    for _ in 1..self.twos {
+      power = self.context.square(&power);
+
+      if power == self.minus_one {
+        return true;
+      }
+      if power == *one {
+        return false;
+      }
+    }
+
+    false
+  }
+}
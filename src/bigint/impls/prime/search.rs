@@ -0,0 +1,140 @@
+use crate::{bigint::BigInt, sieve::SMALL_PRIMES};
+
+// Number of odd candidates sieved at once before any of them is tested.
+const WINDOW: usize = 1024;
+
+impl BigInt {
+  /// The smallest prime strictly greater than `self`.
+  #[inline(always)]
+  pub fn next_prime(&self) -> Self {
+    first_prime_from(&(self + 1))
+  }
+
+  /// The largest prime strictly less than `self`, or `None` if there is
+  /// none.
+  #[inline(always)]
+  pub fn prev_prime(&self) -> Option<Self> {
+    last_prime_from(&(self - 1))
+  }
+
+  /// Iterates over the primes greater than or equal to `self`, in order.
+  #[inline(always)]
+  pub fn primes_from(&self) -> PrimesFrom {
+    PrimesFrom { next: self.clone() }
+  }
+}
+
+/// An endless iterator over successive primes, created by
+/// [`BigInt::primes_from`].
+#[derive(Debug, Clone)]
+pub struct PrimesFrom {
+  next: BigInt,
+}
+
+impl Iterator for PrimesFrom {
+  type Item = BigInt;
+
+  #[inline(always)]
+  fn next(&mut self) -> Option<Self::Item> {
+    let prime = first_prime_from(&self.next);
+    self.next = &prime + 1;
+
+    Some(prime)
+  }
+}
+
+#[inline]
+fn first_prime_from(start: &BigInt) -> BigInt {
+  let two = BigInt::from(2u32);
+  if *start <= two {
+    return two;
+  }
+
+  let mut base = match start.is_even() {
+    true => start + 1,
+    false => start.clone(),
+  };
+
+  loop {
+    let composite = sieve_window(&base, Direction::Up);
+
+    for idx in (0..WINDOW).filter(|idx| !composite[*idx]) {
+      let candidate = &base + (2 * idx) as u32;
+      if candidate.is_prime_bpsw() {
+        return candidate;
+      }
+    }
+
+    base += (2 * WINDOW) as u32;
+  }
+}
+
+#[inline]
+fn last_prime_from(start: &BigInt) -> Option<BigInt> {
+  let two = BigInt::from(2u32);
+  if *start < two {
+    return None;
+  }
+  if *start == two {
+    return Some(two);
+  }
+
+  let mut base = match start.is_even() {
+    true => start - 1,
+    false => start.clone(),
+  };
+
+  // Three is prime, so the search ends before any candidate drops below it.
+  loop {
+    let composite = sieve_window(&base, Direction::Down);
+
+    for idx in (0..WINDOW).filter(|idx| !composite[*idx]) {
+      let candidate = &base - (2 * idx) as u32;
+      if candidate.is_prime_bpsw() {
+        return Some(candidate);
+      }
+    }
+
+    base -= (2 * WINDOW) as u32;
+  }
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+  Up,
+  Down,
+}
+
+// Marks the candidates `base ± 2 * idx` that have an odd small prime factor.
+// Windows reaching down to the small primes themselves are left unmarked,
+// since a candidate there may be one of those primes.
+#[inline]
+fn sieve_window(base: &BigInt, direction: Direction) -> Vec<bool> {
+  let mut composite = vec![false; WINDOW];
+
+  let lowest = match direction {
+    Direction::Up => base.clone(),
+    Direction::Down => base - (2 * (WINDOW - 1)) as u32,
+  };
+  if lowest <= BigInt::from(SMALL_PRIMES[SMALL_PRIMES.len() - 1]) {
+    return composite;
+  }
+
+  for prime in SMALL_PRIMES.into_iter().skip(1) {
+    let prime = prime as u64;
+    let residue = (base % prime as u32) as u64;
+    let half = prime.div_ceil(2);
+
+    // Solves `base ± 2 * idx = 0 (mod prime)` using `2^-1 = (p + 1) / 2`.
+    let first = match direction {
+      Direction::Up => (prime - residue) % prime * half % prime,
+      Direction::Down => residue * half % prime,
+    };
+
+    for idx in (first as usize..WINDOW).step_by(prime as usize) {
+      composite[idx] = true;
+    }
+  }
+
+  composite
+}
@@ -0,0 +1,91 @@
+use crate::{
+  bigint::{BigInt, BigIntError},
+  utils::Sign,
+};
+
+impl BigInt {
+  /// Returns `floor(sqrt(self))`, or an error for negative values.
+  #[inline]
+  pub fn sqrt(&self) -> Result<Self, BigIntError> {
+    if self.sign.is_negative() {
+      return Err(BigIntError::NegativeRadicand);
+    }
+    if self.is_zero() {
+      return Ok(Self::zero());
+    }
+
+    // Newton's iteration from `2^ceil(bits / 2) >= sqrt(self)` decreases
+    // monotonically until it reaches the floor root.
+    let mut root = &Self::one() << self.bit_length().div_ceil(2);
+
+    loop {
+      let mut next = &root + &(self / &root);
+      next >>= 1;
+
+      if next >= root {
+        return Ok(root);
+      }
+      root = next;
+    }
+  }
+
+  /// Returns `(s, r)` with `s = floor(sqrt(self))` and `self = s^2 + r`.
+  #[inline(always)]
+  pub fn sqrt_rem(&self) -> Result<(Self, Self), BigIntError> {
+    let root = self.sqrt()?;
+    let remainder = self - &root.square();
+
+    Ok((root, remainder))
+  }
+
+  /// Returns the floor of the `n`th root. Odd roots of negative values are
+  /// negative and round down, so the root of `-28` is `-4`; even roots of
+  /// negative values are an error.
+  #[inline]
+  pub fn nth_root(&self, n: u32) -> Result<Self, BigIntError> {
+    if n == 0 {
+      return Err(BigIntError::ZeroRootDegree);
+    }
+    if self.sign.is_negative() && n & 1 == 0 {
+      return Err(BigIntError::NegativeRadicand);
+    }
+    if self.is_zero() || n == 1 {
+      return Ok(self.clone());
+    }
+    if n == 2 {
+      return self.sqrt();
+    }
+
+    let radicand = self.abs();
+    let bits = radicand.bit_length();
+
+    let root = if bits <= n as usize {
+      Self::one()
+    } else {
+      let mut root = &Self::one() << bits.div_ceil(n as usize);
+
+      loop {
+        let mut next =
+          &(&root * (n - 1)) + &(&radicand / &root.pow(n as u64 - 1));
+        next /= n;
+
+        if next >= root {
+          break root;
+        }
+        root = next;
+      }
+    };
+
+    Ok(match self.sign {
+      Sign::Negative => {
+        let mut root = root;
+        root.negate();
+        match root.pow(n as u64) == *self {
+          true => root,
+          false => &root - 1,
+        }
+      }
+      _ => root,
+    })
+  }
+}
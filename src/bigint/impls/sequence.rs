@@ -0,0 +1,82 @@
+use crate::bigint::{BigInt, BigIntError};
+
+impl BigInt {
+  /// The `n`th Fibonacci number by fast doubling, from
+  /// `F(2k) = F(k) (2F(k + 1) - F(k))` and `F(2k + 1) = F(k)^2 + F(k + 1)^2`.
+  #[inline(always)]
+  pub fn fibonacci(n: u64) -> Self {
+    fibonacci_pair(n).0
+  }
+
+  /// The `n`th Lucas number, `L(n) = 2F(n + 1) - F(n)`.
+  #[inline(always)]
+  pub fn lucas(n: u64) -> Self {
+    let (current, next) = fibonacci_pair(n);
+    &(&next << 1) - &current
+  }
+
+  /// The Lucas sequences `(U_n(P, Q), V_n(P, Q))` reduced into
+  /// `[0, |modulus|)`. The doubling ladder runs over `U_k` and `U_(k + 1)`
+  /// and never divides, so the modulus need not be odd or prime.
+  #[inline]
+  pub fn lucas_sequence(
+    p: &BigInt,
+    q: &BigInt,
+    n: &BigInt,
+    modulus: &BigInt,
+  ) -> Result<(Self, Self), BigIntError> {
+    if modulus.is_zero() {
+      return Err(BigIntError::ZeroModulus);
+    }
+    if n.sign.is_negative() {
+      return Err(BigIntError::NegativeIndex);
+    }
+
+    let modulus = modulus.abs();
+    let reduce = |value: &Self| value.rem_euclid(&modulus);
+    let p = reduce(p);
+    let q = reduce(q);
+
+    // `U_k` and `U_(k + 1)` for `k` the bits of `n` read so far, doubled by
+    // `U_2k = U_k (2U_(k + 1) - P U_k)` and
+    // `U_(2k + 1) = U_(k + 1)^2 - Q U_k^2`.
+    let mut u = Self::zero();
+    let mut next = reduce(&Self::one());
+
+    for bit in (0..n.bit_length()).rev() {
+      (u, next) = (
+        reduce(&(&u * &(&(&next << 1) - &(&p * &u)))),
+        reduce(&(&next.square() - &(&q * &u.square()))),
+      );
+
+      if n.test_bit(bit) {
+        (u, next) = (next.clone(), reduce(&(&(&p * &next) - &(&q * &u))));
+      }
+    }
+
+    let v = reduce(&(&(&next << 1) - &(&p * &u)));
+    Ok((u, v))
+  }
+}
+
+// `F(n)` and `F(n + 1)`.
+#[inline]
+fn fibonacci_pair(n: u64) -> (BigInt, BigInt) {
+  let mut current = BigInt::zero();
+  let mut next = BigInt::one();
+
+  for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+    let even = &current * &(&(&next << 1) - &current);
+    let odd = &current.square() + &next.square();
+
+    (current, next) = match (n >> bit) & 1 {
+      1 => {
+        let sum = &even + &odd;
+        (odd, sum)
+      }
+      _ => (even, odd),
+    };
+  }
+
+  (current, next)
+}
@@ -0,0 +1,84 @@
+use std::ops::{Shl, ShlAssign, Shr, ShrAssign};
+
+use crate::{
+  bigint::BigInt,
+  utils::{digital_shift_left_u32, digital_shift_right_u32},
+};
+
+impl Shl<usize> for &BigInt {
+  type Output = BigInt;
+
+  #[inline(always)]
+  fn shl(self, rhs: usize) -> Self::Output {
+    BigInt::from_parts(self.sign, digital_shift_left_u32(&self.digits, rhs))
+  }
+}
+
+impl ShlAssign<usize> for BigInt {
+  #[inline(always)]
+  fn shl_assign(&mut self, rhs: usize) {
+    *self = &*self << rhs;
+  }
+}
+
+impl Shr<usize> for &BigInt {
+  type Output = BigInt;
+
+  #[inline(always)]
+  fn shr(self, rhs: usize) -> Self::Output {
+    let mut result =
+      BigInt::from_parts(self.sign, digital_shift_right_u32(&self.digits, rhs));
+
+    if self.sign.is_negative() && self.trailing_zeros() < rhs {
+      result -= 1;
+    }
+
+    result
+  }
+}
+
+impl ShrAssign<usize> for BigInt {
+  #[inline(always)]
+  fn shr_assign(&mut self, rhs: usize) {
+    *self = &*self >> rhs;
+  }
+}
+
+impl BigInt {
+  #[inline(always)]
+  pub fn bit_length(&self) -> usize {
+    match self.is_zero() {
+      true => 0,
+      false => {
+        self.digits.len() * 32
+          - self.digits[self.digits.len() - 1].leading_zeros() as usize
+      }
+    }
+  }
+
+  #[inline(always)]
+  pub fn trailing_zeros(&self) -> usize {
+    match self.digits.iter().position(|digit| *digit != 0) {
+      Some(idx) => idx * 32 + self.digits[idx].trailing_zeros() as usize,
+      None => 0,
+    }
+  }
+
+  #[inline(always)]
+  pub fn test_bit(&self, idx: usize) -> bool {
+    match self.digits.get(idx / 32) {
+      Some(digit) => (digit >> (idx % 32)) & 1 == 1,
+      None => false,
+    }
+  }
+
+  #[inline(always)]
+  pub fn is_even(&self) -> bool {
+    self.digits[0] & 1 == 0
+  }
+
+  #[inline(always)]
+  pub fn is_odd(&self) -> bool {
+    !self.is_even()
+  }
+}
@@ -0,0 +1,99 @@
+use std::mem;
+
+use crate::bigint::{BigInt, BigIntError};
+
+impl BigInt {
+  /// The Jacobi symbol `(self / n)` for odd positive `n`.
+  #[inline]
+  pub fn jacobi(&self, n: &Self) -> Result<i8, BigIntError> {
+    if n.is_zero() {
+      return Err(BigIntError::ZeroModulus);
+    }
+    if n.sign.is_negative() {
+      return Err(BigIntError::NegativeModulus);
+    }
+    if n.is_even() {
+      return Err(BigIntError::EvenModulus);
+    }
+
+    Ok(jacobi_odd(self, n))
+  }
+
+  /// The Legendre symbol `(self / p)` for an odd prime `p`. Primality of `p`
+  /// is not checked; for composite `p` this is the Jacobi symbol.
+  #[inline(always)]
+  pub fn legendre(&self, p: &Self) -> Result<i8, BigIntError> {
+    self.jacobi(p)
+  }
+
+  /// The Kronecker symbol `(self / n)`, which extends the Jacobi symbol to
+  /// every `n`, including even, negative and zero values.
+  #[inline]
+  pub fn kronecker(&self, n: &Self) -> i8 {
+    if n.is_zero() {
+      return match self.digits == [1] {
+        true => 1,
+        false => 0,
+      };
+    }
+    if self.is_even() && n.is_even() {
+      return 0;
+    }
+
+    let twos = n.trailing_zeros();
+    let mut odd = n >> twos;
+    let mut result = 1;
+
+    // `(a / 2)` is `-1` exactly when `a = ±3 (mod 8)`.
+    if twos & 1 == 1 && matches!(residue_mod_8(self), 3 | 5) {
+      result = -result;
+    }
+    if odd.sign.is_negative() {
+      odd.negate();
+      if self.sign.is_negative() {
+        result = -result;
+      }
+    }
+
+    result * jacobi_odd(self, &odd)
+  }
+}
+
+// Strips factors of two from the numerator and flips the pair by quadratic
+// reciprocity, so only the sign bookkeeping depends on residues mod 8.
+#[inline]
+fn jacobi_odd(value: &BigInt, n: &BigInt) -> i8 {
+  let mut numerator = value.rem_euclid(n);
+  let mut denominator = n.clone();
+  let mut result = 1;
+
+  while !numerator.is_zero() {
+    let twos = numerator.trailing_zeros();
+    numerator >>= twos;
+
+    if twos & 1 == 1 && matches!(denominator.digits[0] & 7, 3 | 5) {
+      result = -result;
+    }
+    if numerator.digits[0] & 3 == 3 && denominator.digits[0] & 3 == 3 {
+      result = -result;
+    }
+
+    mem::swap(&mut numerator, &mut denominator);
+    numerator = &numerator % &denominator;
+  }
+
+  match denominator == BigInt::one() {
+    true => result,
+    false => 0,
+  }
+}
+
+#[inline(always)]
+fn residue_mod_8(value: &BigInt) -> u32 {
+  let low = value.digits[0] & 7;
+
+  match value.sign.is_negative() {
+    true => (8 - low) & 7,
+    false => low,
+  }
+}
@@ -1,8 +1,17 @@
 use std::cmp::Ordering;
 
-use crate::utils::Sign;
+use crate::utils::{digital_trim_zeroes, Sign};
 
+pub mod error;
 pub mod impls;
+pub mod montgomery;
+pub mod reciprocal;
+
+pub use crate::utils::BarrettReducer;
+pub use error::BigIntError;
+pub use impls::prime::PrimesFrom;
+pub use montgomery::MontgomeryContext;
+pub use reciprocal::Reciprocal;
 
 #[derive(Debug, Clone)]
 pub struct BigInt {
@@ -13,8 +22,36 @@ pub struct BigInt {
 const U32_MAX_DIGITS: [u8; 10] = [5, 9, 2, 7, 6, 9, 4, 9, 2, 4];
 const U32_MAX_PLUS_ONE_DIGITS: [u8; 10] = [6, 9, 2, 7, 6, 9, 4, 9, 2, 4];
 const KARATSUBA_CROSSOVER: usize = 60;
+// Release timings from `bench_toom_crossovers` in `src/tests/crossovers.rs`,
+// with the NTT tier off: Toom-3 first beats Karatsuba at 240 limbs (156us
+// against 177us) and Toom-4 first beats Toom-3 at 400 (303us against 373us).
+const TOOM_3_CROSSOVER: usize = 240;
+const TOOM_4_CROSSOVER: usize = 400;
+// From `bench_ntt_crossover`: the NTT beats the best Toom tier from 320 limbs
+// on (182us against 220us, 209us against 344us at 400). At 280 the
+// transform length has just doubled and the two tie.
+const NTT_CROSSOVER: usize = 320;
+const BURNIKEL_ZIEGLER_CROSSOVER: usize = 800;
+const NEWTON_CROSSOVER: usize = 100000;
+// From `bench_half_gcd_crossover`: a half-GCD step followed by Lehmer steps
+// beats Lehmer steps alone from 1500 limbs on (28.1ms against 30.8ms), and
+// is 2.3x faster at 8000. `bench_half_gcd_base_crossover` puts every base
+// size from 16 to 256 limbs within 3% of each other.
+const HALF_GCD_CROSSOVER: usize = 1500;
+const HALF_GCD_BASE_CROSSOVER: usize = 64;
 
 impl BigInt {
+  #[inline(always)]
+  pub(crate) fn from_parts(sign: Sign, mut digits: Vec<u32>) -> Self {
+    digital_trim_zeroes(&mut digits);
+
+    if digits == [0] {
+      Self::zero()
+    } else {
+      Self { sign, digits }
+    }
+  }
+
   #[inline(always)]
   pub fn zero() -> Self {
     Self::default()
@@ -52,19 +89,6 @@ impl BigInt {
     self.digits.truncate(last_nonzero + 1);
   }
 
-  #[inline(always)]
-  pub fn fact(&self) -> Self {
-    let mut counter = self.clone();
-    let mut result = BigInt::from(1u64);
-
-    while !counter.is_zero() {
-      result *= &counter;
-      counter -= 1;
-    }
-
-    result
-  }
-
   #[inline(always)]
   pub fn cmp_magnitude(&self, other: &Self) -> Ordering {
     usize::cmp(&self.magnitude(), &other.magnitude())
@@ -80,6 +104,17 @@ impl BigInt {
     }
   }
 
+  #[inline(always)]
+  pub fn abs(&self) -> Self {
+    match self.sign {
+      Sign::Negative => Self {
+        sign: Sign::Positive,
+        digits: self.digits.clone(),
+      },
+      _ => self.clone(),
+    }
+  }
+
   #[inline(always)]
   pub fn zero_out(&mut self) {
     self.sign = Sign::Zero;
@@ -105,4 +140,39 @@ impl BigInt {
   pub const fn karatsuba_crossover() -> usize {
     KARATSUBA_CROSSOVER
   }
+
+  #[inline(always)]
+  pub const fn toom_3_crossover() -> usize {
+    TOOM_3_CROSSOVER
+  }
+
+  #[inline(always)]
+  pub const fn toom_4_crossover() -> usize {
+    TOOM_4_CROSSOVER
+  }
+
+  #[inline(always)]
+  pub const fn ntt_crossover() -> usize {
+    NTT_CROSSOVER
+  }
+
+  #[inline(always)]
+  pub const fn burnikel_ziegler_crossover() -> usize {
+    BURNIKEL_ZIEGLER_CROSSOVER
+  }
+
+  #[inline(always)]
+  pub const fn newton_crossover() -> usize {
+    NEWTON_CROSSOVER
+  }
+
+  #[inline(always)]
+  pub const fn half_gcd_crossover() -> usize {
+    HALF_GCD_CROSSOVER
+  }
+
+  #[inline(always)]
+  pub const fn half_gcd_base_crossover() -> usize {
+    HALF_GCD_BASE_CROSSOVER
+  }
 }
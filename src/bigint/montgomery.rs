@@ -0,0 +1,116 @@
+use std::cmp::Ordering;
+
+use crate::{
+  bigint::{BigInt, BigIntError},
+  utils::{
+    digital_cmp, digital_montgomery_inverse_u32, digital_montgomery_reduce_u32,
+    Sign,
+  },
+};
+
+/// Precomputed state for arithmetic modulo a fixed odd modulus `m` with
+/// `n` limbs. Values in Montgomery form are stored as `x * R mod m` where
+/// `R = 2^(32n)`, so products can be reduced with REDC instead of division.
+#[derive(Debug, Clone)]
+pub struct MontgomeryContext {
+  modulus: BigInt,
+  inverse: u32,
+  r_squared: BigInt,
+  one: BigInt,
+}
+
+impl MontgomeryContext {
+  /// Fails if `modulus` is zero or even. The sign of `modulus` is ignored.
+  #[inline]
+  pub fn new(modulus: &BigInt) -> Result<Self, BigIntError> {
+    if modulus.is_zero() {
+      return Err(BigIntError::ZeroModulus);
+    }
+    if modulus.is_even() {
+      return Err(BigIntError::EvenModulus);
+    }
+
+    let modulus = modulus.abs();
+    let limb_bits = 32 * modulus.magnitude();
+
+    Ok(Self {
+      inverse: digital_montgomery_inverse_u32(modulus.digits[0]),
+      r_squared: (&BigInt::one() << (2 * limb_bits)).rem_euclid(&modulus),
+      one: (&BigInt::one() << limb_bits).rem_euclid(&modulus),
+      modulus,
+    })
+  }
+
+  #[inline(always)]
+  pub fn modulus(&self) -> &BigInt {
+    &self.modulus
+  }
+
+  /// The Montgomery form of one, `R mod m`.
+  #[inline(always)]
+  pub fn one(&self) -> &BigInt {
+    &self.one
+  }
+
+  #[inline(always)]
+  pub fn to_montgomery(&self, value: &BigInt) -> BigInt {
+    self.mul(&value.rem_euclid(&self.modulus), &self.r_squared)
+  }
+
+  #[inline(always)]
+  pub fn from_montgomery(&self, value: &BigInt) -> BigInt {
+    self.reduce(self.residue(value).digits)
+  }
+
+  /// Multiplies two values already in Montgomery form.
+  #[inline(always)]
+  pub fn mul(&self, lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    let (lhs, rhs) = (self.residue(lhs), self.residue(rhs));
+    self.reduce(BigInt::multiply_digits(&lhs.digits, &rhs.digits))
+  }
+
+  #[inline(always)]
+  pub fn square(&self, value: &BigInt) -> BigInt {
+    self.reduce(BigInt::square_digits(&self.residue(value).digits))
+  }
+
+  /// Raises a value in Montgomery form to `exponent`, keeping the result in
+  /// Montgomery form. Panics if `exponent` is negative.
+  #[inline]
+  pub fn pow(&self, base: &BigInt, exponent: &BigInt) -> BigInt {
+    if exponent.sign.is_negative() {
+      panic!("Attempt to raise to a negative power");
+    }
+    if exponent.is_zero() {
+      return self.one.clone();
+    }
+
+    BigInt::sliding_window_pow(
+      base.clone(),
+      exponent,
+      self.one.clone(),
+      |lhs, rhs| self.mul(lhs, rhs),
+      |value| self.square(value),
+    )
+  }
+
+  // REDC only accepts operands in `[0, m)`, so negative or unreduced values
+  // are brought into range first instead of being truncated.
+  #[inline(always)]
+  fn residue(&self, value: &BigInt) -> BigInt {
+    match value.sign.is_negative()
+      || digital_cmp(&value.digits, &self.modulus.digits) != Ordering::Less
+    {
+      true => value.rem_euclid(&self.modulus),
+      false => value.clone(),
+    }
+  }
+
+  #[inline(always)]
+  fn reduce(&self, digits: Vec<u32>) -> BigInt {
+    BigInt::from_parts(
+      Sign::Positive,
+      digital_montgomery_reduce_u32(digits, &self.modulus.digits, self.inverse),
+    )
+  }
+}
@@ -0,0 +1,61 @@
+use crate::{bigint::BigInt, utils::Sign};
+
+/// A divisor together with its fixed-point inverse
+/// `floor(2^(32 * precision_limbs) / |divisor|)`, computed once so that
+/// repeated divisions by the same value cost a multiplication each.
+#[derive(Debug, Clone)]
+pub struct Reciprocal {
+  divisor: BigInt,
+  inverse: BigInt,
+  precision_limbs: usize,
+}
+
+impl Reciprocal {
+  /// Panics if `divisor` is zero.
+  #[inline]
+  pub fn new(divisor: &BigInt, precision_limbs: usize) -> Self {
+    Self {
+      inverse: divisor.reciprocal(precision_limbs),
+      divisor: divisor.clone(),
+      precision_limbs,
+    }
+  }
+
+  #[inline(always)]
+  pub fn divisor(&self) -> &BigInt {
+    &self.divisor
+  }
+
+  #[inline(always)]
+  pub fn precision_limbs(&self) -> usize {
+    self.precision_limbs
+  }
+
+  /// Truncated division of `value` by the divisor, matching
+  /// `value.div_rem(divisor)`. Values of more than `precision_limbs` digits
+  /// fall back to `div_rem`.
+  #[inline]
+  pub fn div_rem(&self, value: &BigInt) -> (BigInt, BigInt) {
+    if value.magnitude() > self.precision_limbs {
+      return value.div_rem(&self.divisor);
+    }
+
+    let (quotient, remainder) = BigInt::reciprocal_divide_digits(
+      &value.digits,
+      &self.divisor.digits,
+      &self.inverse.digits,
+      self.precision_limbs,
+    );
+
+    let quotient_sign = if value.sign == self.divisor.sign {
+      Sign::Positive
+    } else {
+      Sign::Negative
+    };
+
+    (
+      BigInt::from_parts(quotient_sign, quotient),
+      BigInt::from_parts(value.sign, remainder),
+    )
+  }
+}
@@ -0,0 +1,232 @@
+use crate::{
+  bigint::{impls::factorial::factorial, BigInt},
+  sieve::primes_up_to,
+};
+
+/// The binomial coefficient `n choose k`, zero when `k > n`. Each prime's
+/// exponent is counted with Legendre's formula and the prime powers, each at
+/// most `n` by Kummer's theorem, are multiplied out in a balanced product
+/// tree, so no factorial is ever divided.
+#[inline]
+pub fn binomial(n: u64, k: u64) -> BigInt {
+  if k > n {
+    return BigInt::zero();
+  }
+
+  let k = k.min(n - k);
+  let factors: Vec<u64> = primes_up_to(n)
+    .filter_map(|prime| {
+      let exponent =
+        legendre(n, prime) - legendre(k, prime) - legendre(n - k, prime);
+      (exponent > 0).then(|| prime.pow(exponent as u32))
+    })
+    .collect();
+
+  BigInt::product(&factors)
+}
+
+/// The multinomial coefficient `(k_1 + ... + k_m)! / (k_1! ... k_m!)`, by
+/// the same prime exponent counting as [`binomial`]. Panics if the total
+/// overflows a `u64`.
+#[inline]
+pub fn multinomial(ks: &[u64]) -> BigInt {
+  let n = match ks.iter().try_fold(0u64, |total, k| total.checked_add(*k)) {
+    Some(n) => n,
+    None => panic!("Attempt to take a multinomial coefficient above 2^64"),
+  };
+
+  let mut factors = vec![];
+  for prime in primes_up_to(n) {
+    let exponent =
+      legendre(n, prime) - ks.iter().map(|k| legendre(*k, prime)).sum::<u64>();
+    push_prime_power(&mut factors, prime, exponent);
+  }
+
+  BigInt::product(&factors)
+}
+
+/// The `n`th Catalan number, `binomial(2n, n) / (n + 1)`.
+#[inline(always)]
+pub fn catalan(n: u64) -> BigInt {
+  &binomial(2 * n, n) / &BigInt::from(n + 1)
+}
+
+/// The unsigned Stirling number of the first kind `[n k]`, the number of
+/// permutations of `n` elements with exactly `k` cycles, by the recurrence
+/// `[m+1 j] = m [m j] + [m j-1]` over `O(nk)` additions.
+#[inline]
+pub fn stirling_first(n: u64, k: u64) -> BigInt {
+  if k > n {
+    return BigInt::zero();
+  }
+
+  // `row[j]` holds `[m j]` for the current `m`.
+  let mut row = vec![BigInt::zero(); k as usize + 1];
+  row[0] = BigInt::one();
+
+  for m in 0..n {
+    let multiplier = BigInt::from(m);
+    for j in (1..=k.min(m + 1) as usize).rev() {
+      row[j] = &(&row[j] * &multiplier) + &row[j - 1];
+    }
+    row[0] = &row[0] * &multiplier;
+  }
+
+  row.pop().unwrap()
+}
+
+/// The Stirling number of the second kind `{n k}`, the number of partitions
+/// of `n` elements into exactly `k` nonempty sets, by the explicit sum
+/// `sum (-1)^j binomial(k, j) (k - j)^n / k!` over `j` in `0..=k`.
+#[inline]
+pub fn stirling_second(n: u64, k: u64) -> BigInt {
+  if k > n {
+    return BigInt::zero();
+  }
+  if n == 0 {
+    return BigInt::one();
+  }
+
+  let mut sum = BigInt::zero();
+  let mut coefficient = BigInt::one();
+  for j in 0..k {
+    let term = &coefficient * &BigInt::from(k - j).pow(n);
+    sum = match j & 1 {
+      0 => &sum + &term,
+      _ => &sum - &term,
+    };
+    coefficient = &(&coefficient * &BigInt::from(k - j)) / &BigInt::from(j + 1);
+  }
+
+  let primes: Vec<u64> = primes_up_to(k).collect();
+  &sum / &factorial(k, &primes)
+}
+
+/// The `n`th Bell number, the number of partitions of a set of `n` elements,
+/// read off the Bell triangle in `O(n^2)` additions.
+#[inline]
+pub fn bell(n: u64) -> BigInt {
+  let mut row = vec![BigInt::one()];
+
+  for _ in 0..n {
+    let mut next = Vec::with_capacity(row.len() + 1);
+    next.push(row.last().unwrap().clone());
+    for value in &row {
+      let sum = next.last().unwrap() + value;
+      next.push(sum);
+    }
+    row = next;
+  }
+
+  row.swap_remove(0)
+}
+
+/// The number of partitions `p(n)` of `n` into positive parts, by Euler's
+/// pentagonal number recurrence in `O(n^(3/2))` additions.
+#[inline]
+pub fn partitions(n: u64) -> BigInt {
+  let n = n as usize;
+  let mut counts = Vec::with_capacity(n + 1);
+  counts.push(BigInt::one());
+
+  for m in 1..=n {
+    let mut count = BigInt::zero();
+
+    // The generalized pentagonal numbers `j(3j - 1) / 2` and `j(3j + 1) / 2`.
+    for j in 1.. {
+      let low = j * (3 * j - 1) / 2;
+      if low > m {
+        break;
+      }
+
+      let mut term = counts[m - low].clone();
+      let high = low + j;
+      if high <= m {
+        term = &term + &counts[m - high];
+      }
+
+      count = match j & 1 {
+        1 => &count + &term,
+        _ => &count - &term,
+      };
+    }
+
+    counts.push(count);
+  }
+
+  counts.swap_remove(n)
+}
+
+/// The double factorial `n!! = n (n - 2) (n - 4) ...`, one for `n = 0`.
+#[inline]
+pub fn double_factorial(n: u64) -> BigInt {
+  if n & 1 == 0 {
+    let half = n / 2;
+    let primes: Vec<u64> = primes_up_to(half).collect();
+    return &factorial(half, &primes) << half as usize;
+  }
+
+  let odds: Vec<u64> = (1..=n).step_by(2).collect();
+  BigInt::product(&odds)
+}
+
+/// The rising factorial `x (x + 1) ... (x + n - 1)`, one for `n = 0`.
+/// Panics if `x + n - 1` overflows a `u64`.
+#[inline]
+pub fn rising_factorial(x: u64, n: u64) -> BigInt {
+  if n == 0 {
+    return BigInt::one();
+  }
+
+  let last = match x.checked_add(n - 1) {
+    Some(last) => last,
+    None => panic!("Attempt to take a rising factorial past 2^64"),
+  };
+  let values: Vec<u64> = (x..=last).collect();
+
+  BigInt::product(&values)
+}
+
+/// The falling factorial `x (x - 1) ... (x - n + 1)`, one for `n = 0` and
+/// zero when `n > x`.
+#[inline(always)]
+pub fn falling_factorial(x: u64, n: u64) -> BigInt {
+  if n > x {
+    return BigInt::zero();
+  }
+
+  let values: Vec<u64> = (x - n + 1..=x).collect();
+  BigInt::product(&values)
+}
+
+// The exponent of `prime` in `n!`.
+#[inline]
+fn legendre(n: u64, prime: u64) -> u64 {
+  let mut quotient = n;
+  let mut exponent = 0;
+  while quotient >= prime {
+    quotient /= prime;
+    exponent += quotient;
+  }
+
+  exponent
+}
+
+// Appends `prime^exponent` to `factors`, split into word-sized pieces.
+#[inline]
+fn push_prime_power(factors: &mut Vec<u64>, prime: u64, exponent: u64) {
+  let mut power = 1u64;
+  for _ in 0..exponent {
+    power = match power.checked_mul(prime) {
+      Some(next) => next,
+      None => {
+        factors.push(power);
+        prime
+      }
+    };
+  }
+
+  if power > 1 {
+    factors.push(power);
+  }
+}
@@ -0,0 +1,277 @@
+use crate::{
+  bigint::{BigInt, MontgomeryContext},
+  sieve::primes_up_to,
+  utils::SplitMix64,
+};
+
+// Stage 2 pairs each prime `q` with the giant step `m * D` nearest to it,
+// leaving a baby step `j = |q - m * D| < D / 2`.
+const GIANT_STEP: u64 = 210;
+
+/// Bounds and curve count for Lenstra's elliptic-curve method. Stage 1
+/// multiplies by every prime power up to `b1`; stage 2 looks for a single
+/// further prime in `(b1, b2]`. The same `seed` always picks the same curves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EcmParams {
+  pub b1: u64,
+  pub b2: u64,
+  pub curves: u32,
+  pub seed: u64,
+}
+
+impl Default for EcmParams {
+  /// Tuned for factors of around 25 digits.
+  fn default() -> Self {
+    Self {
+      b1: 50000,
+      b2: 5000000,
+      curves: 200,
+      seed: 0x5eed,
+    }
+  }
+}
+
+/// Searches for a proper divisor of an odd composite `n` that is not a
+/// perfect power, trying up to `params.curves` Montgomery curves chosen with
+/// Suyama's parametrization. Returns `None` for even `n` or if every curve
+/// fails.
+#[inline]
+pub fn ecm(n: &BigInt, params: &EcmParams) -> Option<BigInt> {
+  let context = MontgomeryContext::new(n).ok()?;
+  let primes: Vec<u64> = primes_up_to(params.b2.max(params.b1)).collect();
+  let mut rng = SplitMix64::new(params.seed);
+
+  for _ in 0..params.curves {
+    let sigma = BigInt::from(6 + (rng.next_u64() >> 2));
+
+    let divisor = match Curve::suyama(&context, &sigma) {
+      Ok((curve, point)) => curve.find_divisor(point, &primes, params),
+      Err(divisor) => divisor,
+    };
+
+    if let Some(divisor) = divisor.filter(|divisor| divisor != n) {
+      return Some(divisor);
+    }
+  }
+
+  None
+}
+
+// A point in `(X : Z)` coordinates, with both kept in Montgomery form.
+#[derive(Clone)]
+struct Point {
+  x: BigInt,
+  z: BigInt,
+}
+
+// The curve `B y^2 = x^3 + A x^2 + x` modulo `n`, which only needs
+// `(A + 2) / 4` for its x-only arithmetic.
+struct Curve<'a> {
+  context: &'a MontgomeryContext,
+  a24: BigInt,
+}
+
+impl<'a> Curve<'a> {
+  // Suyama's family: `u = sigma^2 - 5`, `v = 4 sigma`, starting point
+  // `(u^3 : v^3)` and `(A + 2) / 4 = (v - u)^3 (3u + v) / (16 u^3 v)`. A
+  // failed inversion can itself reveal a divisor, returned as the error.
+  #[inline]
+  fn suyama(
+    context: &'a MontgomeryContext,
+    sigma: &BigInt,
+  ) -> Result<(Self, Point), Option<BigInt>> {
+    let n = context.modulus();
+    let u = (&sigma.square() - 5).rem_euclid(n);
+    let v = (sigma << 2).rem_euclid(n);
+    let u_cubed = u.pow(3).rem_euclid(n);
+
+    let numerator = (&(&v - &u).pow(3) * &(&(&u * 3) + &v)).rem_euclid(n);
+    let denominator = (&(&u_cubed * &v) << 4).rem_euclid(n);
+
+    let inverse = denominator.mod_inverse(n).map_err(|_| {
+      let divisor = denominator.gcd(n);
+      (divisor != BigInt::one()).then_some(divisor)
+    })?;
+    let a24 = (&numerator * &inverse).rem_euclid(n);
+
+    let curve = Self {
+      context,
+      a24: context.to_montgomery(&a24),
+    };
+    let point = Point {
+      x: context.to_montgomery(&u_cubed),
+      z: context.to_montgomery(&v.pow(3)),
+    };
+
+    Ok((curve, point))
+  }
+
+  #[inline]
+  fn find_divisor(
+    &self,
+    point: Point,
+    primes: &[u64],
+    params: &EcmParams,
+  ) -> Option<BigInt> {
+    let n = self.context.modulus();
+    let one = BigInt::one();
+
+    let point = self.stage_one(point, primes, params.b1);
+    let divisor = point.z.gcd(n);
+    if divisor != one {
+      return Some(divisor);
+    }
+
+    let divisor = self.stage_two(&point, primes, params).gcd(n);
+    (divisor != one).then_some(divisor)
+  }
+
+  // Multiplies by the largest power of each prime up to `b1`.
+  #[inline]
+  fn stage_one(&self, mut point: Point, primes: &[u64], b1: u64) -> Point {
+    for &prime in primes.iter().take_while(|prime| **prime <= b1) {
+      let mut power = prime;
+      while power <= b1 / prime {
+        power *= prime;
+      }
+      point = self.ladder(&point, power);
+    }
+
+    point
+  }
+
+  // For each prime `q = m * D ± j` in `(b1, b2]`, `q * Q` is the identity
+  // modulo a prime factor exactly when `x(m D Q) = x(j Q)` there, so the
+  // cross products `X_mD Z_j - X_j Z_mD` are accumulated for one final gcd.
+  #[inline]
+  fn stage_two(
+    &self,
+    point: &Point,
+    primes: &[u64],
+    params: &EcmParams,
+  ) -> BigInt {
+    let half = GIANT_STEP / 2;
+
+    // Baby steps `j Q` for odd `j < D / 2`, at index `j / 2`.
+    let doubled = self.double(point);
+    let mut baby_steps = vec![point.clone(), self.add(&doubled, point, point)];
+    while (baby_steps.len() as u64) < half / 2 {
+      let len = baby_steps.len();
+      let next = self.add(&baby_steps[len - 1], &doubled, &baby_steps[len - 2]);
+      baby_steps.push(next);
+    }
+
+    let giant_step = self.ladder(point, GIANT_STEP);
+    let mut multiple = (params.b1 + half) / GIANT_STEP;
+    let mut previous =
+      self.ladder(point, multiple.max(1) * GIANT_STEP - GIANT_STEP);
+    let mut current = self.ladder(point, multiple * GIANT_STEP);
+    let mut product = self.context.one().clone();
+
+    for &prime in primes.iter().skip_while(|prime| **prime <= params.b1) {
+      if prime > params.b2 {
+        break;
+      }
+
+      let target = (prime + half) / GIANT_STEP;
+      while multiple < target {
+        let next = match multiple {
+          0 => giant_step.clone(),
+          1 => self.double(&giant_step),
+          _ => self.add(&current, &giant_step, &previous),
+        };
+        (previous, current) = (current, next);
+        multiple += 1;
+      }
+
+      let baby =
+        &baby_steps[(prime.abs_diff(multiple * GIANT_STEP) / 2) as usize];
+      let cross = self.subtract(
+        &self.context.mul(&current.x, &baby.z),
+        &self.context.mul(&baby.x, &current.z),
+      );
+      product = self.context.mul(&product, &cross);
+    }
+
+    product
+  }
+
+  // The Montgomery ladder, keeping `R1 - R0 = P` throughout.
+  #[inline]
+  fn ladder(&self, point: &Point, scalar: u64) -> Point {
+    if scalar == 0 {
+      return Point {
+        x: self.context.one().clone(),
+        z: BigInt::zero(),
+      };
+    }
+
+    let mut low = point.clone();
+    let mut high = self.double(point);
+
+    for bit in (0..63 - scalar.leading_zeros()).rev() {
+      if (scalar >> bit) & 1 == 1 {
+        low = self.add(&high, &low, point);
+        high = self.double(&high);
+      } else {
+        high = self.add(&low, &high, point);
+        low = self.double(&low);
+      }
+    }
+
+    low
+  }
+
+  #[inline]
+  fn double(&self, point: &Point) -> Point {
+    let context = self.context;
+    let sum = context.square(&self.sum(&point.x, &point.z));
+    let difference = context.square(&self.subtract(&point.x, &point.z));
+    let cross = self.subtract(&sum, &difference);
+
+    Point {
+      x: context.mul(&sum, &difference),
+      z: context.mul(
+        &cross,
+        &self.sum(&difference, &context.mul(&self.a24, &cross)),
+      ),
+    }
+  }
+
+  // Differential addition: `lhs + rhs` given `lhs - rhs`.
+  #[inline]
+  fn add(&self, lhs: &Point, rhs: &Point, difference: &Point) -> Point {
+    let context = self.context;
+    let first =
+      context.mul(&self.subtract(&lhs.x, &lhs.z), &self.sum(&rhs.x, &rhs.z));
+    let second =
+      context.mul(&self.sum(&lhs.x, &lhs.z), &self.subtract(&rhs.x, &rhs.z));
+
+    Point {
+      x: context
+        .mul(&difference.z, &context.square(&self.sum(&first, &second))),
+      z: context.mul(
+        &difference.x,
+        &context.square(&self.subtract(&first, &second)),
+      ),
+    }
+  }
+
+  #[inline(always)]
+  fn sum(&self, lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    let sum = lhs + rhs;
+    match sum >= *self.context.modulus() {
+      true => &sum - self.context.modulus(),
+      false => sum,
+    }
+  }
+
+  #[inline(always)]
+  fn subtract(&self, lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    let difference = lhs - rhs;
+    match difference.sign.is_negative() {
+      true => &difference + self.context.modulus(),
+      false => difference,
+    }
+  }
+}
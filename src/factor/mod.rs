@@ -0,0 +1,191 @@
+mod ecm;
+mod rho;
+#[cfg(feature = "siqs")]
+pub(crate) mod siqs;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{bigint::BigInt, sieve::SMALL_PRIMES, utils::Sign};
+
+pub use ecm::{ecm, EcmParams};
+#[cfg(feature = "siqs")]
+pub use siqs::siqs;
+
+// Iterations of Pollard's rho, enough for factors up to about ten digits,
+// before handing a cofactor to the elliptic-curve method.
+const RHO_MAX_STEPS: usize = 1 << 18;
+
+/// The prime factorization of an integer: its sign and its prime factors in
+/// increasing order, each with its multiplicity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Factorization {
+  sign: Sign,
+  factors: Vec<(BigInt, u32)>,
+}
+
+impl Factorization {
+  #[inline(always)]
+  pub fn sign(&self) -> Sign {
+    self.sign
+  }
+
+  #[inline(always)]
+  pub fn factors(&self) -> &[(BigInt, u32)] {
+    &self.factors
+  }
+
+  /// Multiplies the factors back into the original value.
+  #[inline]
+  pub fn product(&self) -> BigInt {
+    if self.sign.is_zero() {
+      return BigInt::zero();
+    }
+
+    let mut product = self
+      .factors
+      .iter()
+      .fold(BigInt::one(), |product, (prime, exponent)| {
+        &product * &prime.pow(*exponent as u64)
+      });
+    if self.sign.is_negative() {
+      product.negate();
+    }
+
+    product
+  }
+}
+
+impl From<&Factorization> for BigInt {
+  #[inline(always)]
+  fn from(factorization: &Factorization) -> Self {
+    factorization.product()
+  }
+}
+
+impl Display for Factorization {
+  /// Writes factors as `2^3 * 5 * 7919`, with a leading `-1` for negative
+  /// values and a bare `1` or `0` for those values.
+  #[inline(always)]
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+    let mut terms: Vec<String> = self
+      .factors
+      .iter()
+      .map(|(prime, exponent)| match exponent {
+        1 => format!("{prime}"),
+        _ => format!("{prime}^{exponent}"),
+      })
+      .collect();
+
+    match self.sign {
+      Sign::Zero => terms = vec!["0".to_string()],
+      Sign::Negative => terms.insert(0, "-1".to_string()),
+      Sign::Positive if terms.is_empty() => terms.push("1".to_string()),
+      Sign::Positive => {}
+    }
+
+    write!(formatter, "{}", terms.join(" * "))
+  }
+}
+
+/// Factors `value` completely by trial division, perfect-power detection,
+/// Pollard's rho with Brent's cycle detection and, for cofactors rho cannot
+/// split quickly, the elliptic-curve method with default parameters. Zero has
+/// no prime factors and is returned as an empty factorization with a zero
+/// sign.
+#[inline(always)]
+pub fn factorize(value: &BigInt) -> Factorization {
+  factorize_with(value, &EcmParams::default())
+}
+
+/// Like [`factorize`], with the given bounds and seed for the ECM stage.
+#[inline]
+pub fn factorize_with(value: &BigInt, params: &EcmParams) -> Factorization {
+  let mut factors = vec![];
+  let mut remaining = value.abs();
+
+  if value.is_zero() {
+    return Factorization {
+      sign: Sign::Zero,
+      factors,
+    };
+  }
+
+  for prime in SMALL_PRIMES {
+    let mut exponent = 0;
+    while &remaining % prime == 0 {
+      remaining /= prime;
+      exponent += 1;
+    }
+    if exponent > 0 {
+      factors.push((BigInt::from(prime), exponent));
+    }
+  }
+
+  split(remaining, 1, params, &mut factors);
+
+  factors.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+  factors.dedup_by(|next, kept| {
+    let duplicate = next.0 == kept.0;
+    if duplicate {
+      kept.1 += next.1;
+    }
+    duplicate
+  });
+
+  Factorization {
+    sign: value.sign,
+    factors,
+  }
+}
+
+// Splits a value with no small factors into primes, recording each with
+// `multiplicity` times the exponent it has in `value`.
+#[inline]
+fn split(
+  value: BigInt,
+  multiplicity: u32,
+  params: &EcmParams,
+  factors: &mut Vec<(BigInt, u32)>,
+) {
+  if value == BigInt::one() {
+    return;
+  }
+  if value.is_prime_bpsw() {
+    factors.push((value, multiplicity));
+    return;
+  }
+
+  if let Some((root, exponent)) = perfect_power(&value) {
+    split(root, multiplicity * exponent, params, factors);
+    return;
+  }
+
+  // ECM can run out of curves, but rho without a limit always succeeds.
+  let divisor = rho::pollard_brent(&value, RHO_MAX_STEPS)
+    .or_else(|| ecm(&value, params))
+    .or_else(|| rho::pollard_brent(&value, usize::MAX))
+    .expect("rho always splits an odd composite non-power");
+  let cofactor = &value / &divisor;
+
+  split(divisor, multiplicity, params, factors);
+  split(cofactor, multiplicity, params, factors);
+}
+
+// Finds `value = root^exponent` for the smallest prime `exponent` that
+// works. Every prime factor exceeds the trial-division bound, so exponents
+// above `bits / 8` are impossible.
+#[inline]
+fn perfect_power(value: &BigInt) -> Option<(BigInt, u32)> {
+  let max_exponent = value.bit_length() as u32 / 8;
+
+  SMALL_PRIMES
+    .into_iter()
+    .take_while(|exponent| *exponent <= max_exponent)
+    .find_map(|exponent| {
+      let root = value.nth_root(exponent).ok()?;
+      match root.pow(exponent as u64) == *value {
+        true => Some((root, exponent)),
+        false => None,
+      }
+    })
+}
@@ -0,0 +1,73 @@
+use crate::bigint::{BigInt, MontgomeryContext};
+
+// Differences multiplied together between gcd computations.
+const BATCH: usize = 128;
+
+// Pollard's rho with Brent's cycle detection on `x -> x^2 + c`, batching the
+// gcds. Looks for a proper divisor of an odd composite `n` that is not a
+// perfect power, retrying with the next `c` when a cycle closes without one,
+// and gives up after roughly `max_steps` iterations of the map.
+#[inline]
+pub(super) fn pollard_brent(n: &BigInt, max_steps: usize) -> Option<BigInt> {
+  let context =
+    MontgomeryContext::new(n).expect("trial division leaves odd values");
+  let one = BigInt::one();
+  let mut total_steps = 0usize;
+
+  for increment in 1u32.. {
+    let increment = context.to_montgomery(&BigInt::from(increment));
+    let step = |value: &BigInt| {
+      (&context.square(value) + &increment).rem_euclid(context.modulus())
+    };
+
+    let mut y = context.to_montgomery(&BigInt::from(2u32));
+    let mut x = y.clone();
+    let mut saved = y.clone();
+    let mut product = context.one().clone();
+    let mut divisor = one.clone();
+    let mut cycle = 1;
+
+    while divisor == one {
+      if total_steps > max_steps {
+        return None;
+      }
+      total_steps = total_steps.saturating_add(2 * cycle);
+
+      x = y.clone();
+      for _ in 0..cycle {
+        y = step(&y);
+      }
+
+      let mut steps = 0;
+      while steps < cycle && divisor == one {
+        saved = y.clone();
+        for _ in 0..BATCH.min(cycle - steps) {
+          y = step(&y);
+          product = context.mul(&product, &(&x - &y).abs());
+        }
+
+        divisor = product.gcd(n);
+        steps += BATCH;
+      }
+
+      cycle *= 2;
+    }
+
+    // The batch overshot into a multiple of `n`; redo it one step at a time.
+    if divisor == *n {
+      loop {
+        saved = step(&saved);
+        divisor = (&x - &saved).gcd(n);
+        if divisor != one {
+          break;
+        }
+      }
+    }
+
+    if divisor != *n {
+      return Some(divisor);
+    }
+  }
+
+  unreachable!("every increment has been tried")
+}
@@ -0,0 +1,113 @@
+use crate::{bigint::BigInt, sieve::primes_up_to};
+
+// A prime `p` with `(n / p) = 1`, a square root `t` of `n` modulo `p` and
+// `log2(p)` rounded for the sieve.
+pub(super) struct FactorBasePrime {
+  pub(super) prime: u32,
+  pub(super) sqrt_n: u32,
+  pub(super) log: u8,
+}
+
+// Collects `size` primes over which `n` is a quadratic residue, starting
+// with 2. Returns a prime dividing `n` as the error if one turns up.
+#[inline]
+pub(super) fn factor_base(
+  n: &BigInt,
+  size: usize,
+) -> Result<Vec<FactorBasePrime>, u32> {
+  // About half of all primes qualify, so this bound is usually enough; it
+  // doubles until it is.
+  let mut limit = 32 * size as u64;
+
+  loop {
+    let mut primes = vec![FactorBasePrime {
+      prime: 2,
+      sqrt_n: 1,
+      log: 1,
+    }];
+
+    for candidate in primes_up_to(limit).skip(1).map(|prime| prime as u32) {
+      if primes.len() == size {
+        return Ok(primes);
+      }
+
+      let residue = (n % candidate) as u32;
+      if residue == 0 {
+        return Err(candidate);
+      }
+
+      if let Some(sqrt_n) = sqrt_mod(residue, candidate) {
+        primes.push(FactorBasePrime {
+          prime: candidate,
+          sqrt_n,
+          log: (candidate as f64).log2().round() as u8,
+        });
+      }
+    }
+
+    if primes.len() == size {
+      return Ok(primes);
+    }
+    limit *= 2;
+  }
+}
+
+#[inline]
+pub(super) fn pow_mod(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+  let mut base = base % modulus;
+  let mut result = 1 % modulus;
+
+  while exponent > 0 {
+    if exponent & 1 == 1 {
+      result = result * base % modulus;
+    }
+    base = base * base % modulus;
+    exponent >>= 1;
+  }
+
+  result
+}
+
+// The inverse of `value` modulo a prime, by Fermat's little theorem.
+#[inline(always)]
+pub(super) fn inverse_mod(value: u64, prime: u64) -> u64 {
+  pow_mod(value, prime - 2, prime)
+}
+
+// Tonelli-Shanks for an odd prime `p`, or `None` for non-residues.
+#[inline]
+fn sqrt_mod(residue: u32, prime: u32) -> Option<u32> {
+  let (residue, prime) = (residue as u64, prime as u64);
+
+  if pow_mod(residue, (prime - 1) / 2, prime) != 1 {
+    return None;
+  }
+
+  let twos = (prime - 1).trailing_zeros();
+  let odd = (prime - 1) >> twos;
+  let non_residue = (2..)
+    .find(|value| pow_mod(*value, (prime - 1) / 2, prime) == prime - 1)
+    .expect("half of the nonzero residues are non-residues");
+
+  let mut order = twos;
+  let mut generator = pow_mod(non_residue, odd, prime);
+  let mut power = pow_mod(residue, odd, prime);
+  let mut root = pow_mod(residue, odd.div_ceil(2), prime);
+
+  while power != 1 {
+    let mut least = 0;
+    let mut squared = power;
+    while squared != 1 {
+      squared = squared * squared % prime;
+      least += 1;
+    }
+
+    let step = pow_mod(generator, 1 << (order - least - 1), prime);
+    order = least;
+    generator = step * step % prime;
+    power = power * generator % prime;
+    root = root * step % prime;
+  }
+
+  Some(root as u32)
+}
@@ -0,0 +1,138 @@
+// Rows kept beyond the number of columns still in use once pruning is done.
+// Each one leaves at least one dependency, so this bounds how many the
+// square root step gets to try.
+const SURPLUS_ROWS: usize = 64;
+
+// Gaussian elimination over GF(2). Each row is an exponent vector reduced
+// mod 2, paired with the set of original rows it is the sum of; rows that
+// reduce to zero give subsets whose exponent vectors sum to even values.
+// Rows and columns that cannot take part in a dependency are pruned first,
+// which for sieve matrices removes a large share of both, and columns are
+// renumbered lightest first so that early pivots touch few rows.
+#[inline]
+pub(crate) fn dependencies(
+  rows: &[Vec<usize>],
+  columns: usize,
+) -> Vec<Vec<usize>> {
+  let (kept, weights) = prune(rows, columns);
+
+  let mut order: Vec<usize> =
+    (0..columns).filter(|column| weights[*column] != 0).collect();
+  order.sort_by_key(|column| weights[*column]);
+  let mut renumbered = vec![0; columns];
+  for (new, column) in order.iter().enumerate() {
+    renumbered[*column] = new;
+  }
+
+  let column_words = order.len().div_ceil(64);
+  let history_words = kept.len().div_ceil(64);
+
+  let mut matrix: Vec<(Vec<u64>, Vec<u64>)> = kept
+    .iter()
+    .enumerate()
+    .map(|(idx, row)| {
+      let mut bits = vec![0u64; column_words];
+      for column in &rows[*row] {
+        let column = renumbered[*column];
+        bits[column / 64] ^= 1 << (column % 64);
+      }
+
+      let mut history = vec![0u64; history_words];
+      history[idx / 64] |= 1 << (idx % 64);
+
+      (bits, history)
+    })
+    .collect();
+
+  let mut rank = 0;
+  for column in 0..order.len() {
+    let (word, bit) = (column / 64, 1u64 << (column % 64));
+
+    let Some(pivot) =
+      (rank..matrix.len()).find(|idx| matrix[*idx].0[word] & bit != 0)
+    else {
+      continue;
+    };
+    matrix.swap(rank, pivot);
+
+    // Columns before this one are already clear in every row below.
+    let (pivot_bits, pivot_history) = matrix[rank].clone();
+    for (bits, history) in matrix.iter_mut().skip(rank + 1) {
+      if bits[word] & bit != 0 {
+        xor_into(&mut bits[word..], &pivot_bits[word..]);
+        xor_into(history, &pivot_history);
+      }
+    }
+
+    rank += 1;
+  }
+
+  matrix
+    .into_iter()
+    .skip(rank)
+    .map(|(_, history)| {
+      (0..kept.len())
+        .filter(|idx| history[idx / 64] & (1 << (idx % 64)) != 0)
+        .map(|idx| kept[idx])
+        .collect()
+    })
+    .collect()
+}
+
+// A row holding the only odd entry of some column is in no dependency, so it
+// is dropped, which may leave other columns with a single entry. Once none
+// is left, rows past `SURPLUS_ROWS` more than the columns in use are dropped
+// too, and pruning resumes. Returns the kept rows and each column's weight
+// among them.
+#[inline]
+fn prune(rows: &[Vec<usize>], columns: usize) -> (Vec<usize>, Vec<usize>) {
+  let mut column_rows = vec![Vec::new(); columns];
+  for (idx, row) in rows.iter().enumerate() {
+    for column in row {
+      column_rows[*column].push(idx);
+    }
+  }
+
+  let mut weights: Vec<usize> = column_rows.iter().map(Vec::len).collect();
+  let mut used_columns = weights.iter().filter(|weight| **weight != 0).count();
+  let mut kept = vec![true; rows.len()];
+  let mut kept_rows = rows.len();
+
+  let mut singletons: Vec<usize> =
+    (0..columns).filter(|column| weights[*column] == 1).collect();
+  let mut last = rows.len();
+  loop {
+    let row = if let Some(column) = singletons.pop() {
+      if weights[column] != 1 {
+        continue;
+      }
+      column_rows[column].iter().copied().find(|row| kept[*row]).unwrap()
+    } else if kept_rows > used_columns + SURPLUS_ROWS {
+      last = (0..last).rev().find(|row| kept[*row]).unwrap();
+      last
+    } else {
+      break;
+    };
+
+    kept[row] = false;
+    kept_rows -= 1;
+    for column in &rows[row] {
+      weights[*column] -= 1;
+      match weights[*column] {
+        0 => used_columns -= 1,
+        1 => singletons.push(*column),
+        _ => {}
+      }
+    }
+  }
+
+  let kept = (0..rows.len()).filter(|row| kept[*row]).collect();
+  (kept, weights)
+}
+
+#[inline(always)]
+fn xor_into(target: &mut [u64], source: &[u64]) {
+  for (target, source) in target.iter_mut().zip(source) {
+    *target ^= source;
+  }
+}
@@ -0,0 +1,208 @@
+mod factor_base;
+pub(crate) mod matrix;
+mod polynomial;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{bigint::BigInt, utils::SplitMix64};
+use factor_base::{factor_base, FactorBasePrime};
+use polynomial::Polynomial;
+
+// Partial relations whose cofactor is a prime below this multiple of the
+// largest factor-base prime are kept and paired up by that prime.
+const LARGE_PRIME_MULTIPLIER: u64 = 64;
+// Relations collected beyond the number of matrix columns.
+const EXTRA_RELATIONS: usize = 24;
+// Extra bits of slack in the sieve threshold for rounded logarithms and
+// unsieved primes.
+const THRESHOLD_SLACK: f64 = 3.0;
+
+/// The self-initializing quadratic sieve, for balanced composites of roughly
+/// 20 to 80 digits. Returns a proper divisor of an odd composite `n` that is
+/// not a perfect power, or `None` for values below `2^64`, which are left to
+/// Pollard's rho, or if every dependency found is trivial.
+#[inline]
+pub fn siqs(n: &BigInt) -> Option<BigInt> {
+  let bits = n.bit_length();
+  if bits <= 64 || n.is_even() {
+    return None;
+  }
+
+  let (base_size, half_width) = parameters(bits);
+  let base = match factor_base(n, base_size) {
+    Ok(base) => base,
+    Err(prime) => return Some(BigInt::from(prime)),
+  };
+
+  let relations = collect_relations(n, &base, half_width)?;
+  let rows: Vec<Vec<usize>> = relations
+    .iter()
+    .map(|relation| {
+      relation
+        .exponents
+        .iter()
+        .filter(|(_, exponent)| exponent & 1 == 1)
+        .map(|(column, _)| *column)
+        .collect()
+    })
+    .collect();
+
+  matrix::dependencies(&rows, base.len() + 1)
+    .into_iter()
+    .find_map(|dependency| {
+      let divisor = square_root_divisor(n, &base, &relations, &dependency);
+      let trivial = divisor == BigInt::one() || divisor == *n;
+      (!trivial).then_some(divisor)
+    })
+}
+
+// Factor base size and sieve half-width by the bit length of `n`.
+#[inline]
+fn parameters(bits: usize) -> (usize, i64) {
+  match bits {
+    0..=100 => (120, 32768),
+    101..=128 => (450, 32768),
+    129..=160 => (1200, 32768),
+    161..=183 => (2000, 32768),
+    184..=200 => (3000, 32768),
+    201..=233 => (6000, 98304),
+    _ => (12000, 98304),
+  }
+}
+
+// `y^2 = sign * product(p^e) * cofactor^2 (mod n)`. Column zero holds the
+// sign and column `i + 1` the exponent of the `i`th factor-base prime.
+struct Relation {
+  y: BigInt,
+  exponents: Vec<(usize, u32)>,
+  cofactor: BigInt,
+}
+
+#[inline]
+fn collect_relations(
+  n: &BigInt,
+  base: &[FactorBasePrime],
+  half_width: i64,
+) -> Option<Vec<Relation>> {
+  let largest = base[base.len() - 1].prime as u64;
+  let large_prime_bound = largest * LARGE_PRIME_MULTIPLIER;
+  let wanted = base.len() + 1 + EXTRA_RELATIONS;
+
+  // `|Q(x)|` peaks near `M * sqrt(n / 2)`; candidates must be within a
+  // large prime of being smooth.
+  let threshold = (n.bit_length() as f64 / 2.0 + (half_width as f64).log2()
+    - 0.5
+    - (large_prime_bound as f64).log2()
+    - THRESHOLD_SLACK)
+    .max(0.0) as u8;
+
+  let mut rng = SplitMix64::new(n.digits[0] as u64);
+  let mut used = HashSet::new();
+  let mut seen = HashSet::new();
+  let mut partials: HashMap<u64, Relation> = HashMap::new();
+  let mut relations = vec![];
+  let mut sieve = vec![0u8; 2 * half_width as usize];
+
+  while relations.len() < wanted {
+    let mut polynomial =
+      Polynomial::choose(n, base, half_width, &mut rng, &mut used)?;
+
+    for idx in 0..polynomial.count() {
+      if idx > 0 {
+        polynomial.advance(idx);
+      }
+
+      polynomial.sieve(&mut sieve, half_width);
+
+      for (position, _) in sieve
+        .iter()
+        .enumerate()
+        .filter(|(_, log)| **log >= threshold)
+      {
+        let x = position as i64 - half_width;
+        let Some(relation) = polynomial.relation(x, large_prime_bound) else {
+          continue;
+        };
+        if !seen.insert(relation.y.abs().digits.clone()) {
+          continue;
+        }
+
+        match relation.cofactor.magnitude() == 1
+          && relation.cofactor.digits[0] == 1
+        {
+          true => relations.push(relation),
+          false => {
+            let large_prime = to_u64(&relation.cofactor);
+            match partials.remove(&large_prime) {
+              Some(other) => relations.push(combine(n, relation, other)),
+              None => {
+                partials.insert(large_prime, relation);
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  Some(relations)
+}
+
+// Two partial relations with the same large prime `L` multiply into a full
+// one, with `L` moving to the square-root side.
+#[inline]
+fn combine(n: &BigInt, lhs: Relation, rhs: Relation) -> Relation {
+  let mut exponents: HashMap<usize, u32> = HashMap::new();
+  for (column, exponent) in lhs.exponents.into_iter().chain(rhs.exponents) {
+    *exponents.entry(column).or_default() += exponent;
+  }
+
+  let mut exponents: Vec<(usize, u32)> = exponents.into_iter().collect();
+  exponents.sort_unstable();
+
+  Relation {
+    y: (&lhs.y * &rhs.y).rem_euclid(n),
+    exponents,
+    cofactor: lhs.cofactor,
+  }
+}
+
+// Multiplies the relations of a dependency into `X^2 = Y^2 (mod n)` and
+// returns `gcd(X - Y, n)`.
+#[inline]
+fn square_root_divisor(
+  n: &BigInt,
+  base: &[FactorBasePrime],
+  relations: &[Relation],
+  dependency: &[usize],
+) -> BigInt {
+  let mut x = BigInt::one();
+  let mut y = BigInt::one();
+  let mut totals = vec![0u64; base.len() + 1];
+
+  for relation in dependency.iter().map(|idx| &relations[*idx]) {
+    x = (&x * &relation.y).rem_euclid(n);
+    y = (&y * &relation.cofactor).rem_euclid(n);
+    for (column, exponent) in &relation.exponents {
+      totals[*column] += *exponent as u64;
+    }
+  }
+
+  for (prime, total) in base.iter().zip(totals.into_iter().skip(1)) {
+    let power = BigInt::from(prime.prime)
+      .modpow(&BigInt::from(total / 2), n)
+      .expect("the modulus is nonzero");
+    y = (&y * &power).rem_euclid(n);
+  }
+
+  (&x - &y).gcd(n)
+}
+
+#[inline(always)]
+fn to_u64(value: &BigInt) -> u64 {
+  value
+    .digits
+    .iter()
+    .rev()
+    .fold(0, |result, digit| (result << 32) | *digit as u64)
+}
@@ -0,0 +1,307 @@
+use std::collections::HashSet;
+
+use super::{
+  factor_base::{inverse_mod, FactorBasePrime},
+  Relation,
+};
+use crate::{bigint::BigInt, utils::SplitMix64};
+
+// Preferred size of the primes multiplied into `A`.
+const A_FACTOR_BITS: usize = 11;
+// Attempts at drawing an `A` whose factors have not been used before.
+const A_ATTEMPTS: usize = 100;
+
+// The family `Q(x) = A x^2 + 2 B x + C` with `(A x + B)^2 - n = A Q(x)`.
+// `A` is a product of `s` factor-base primes `q_l` and each of the
+// `2^(s - 1)` choices of signs in `B = ±B_1 ± ... ± B_s` gives a member, so
+// switching between members only shifts each sieve root by a precomputed
+// amount.
+pub(super) struct Polynomial<'a> {
+  n: &'a BigInt,
+  base: &'a [FactorBasePrime],
+  a: BigInt,
+  a_factors: Vec<usize>,
+  b: BigInt,
+  c: BigInt,
+  b_terms: Vec<BigInt>,
+  // `A^-1 mod p`, or zero for primes that are not sieved.
+  a_inverses: Vec<u32>,
+  // `2 B_l A^-1 mod p` for each `l` and each prime.
+  root_steps: Vec<Vec<u32>>,
+  // The two solutions of `Q(x) = 0 (mod p)`.
+  roots: Vec<(u32, u32)>,
+}
+
+impl<'a> Polynomial<'a> {
+  // Draws `A` close to `sqrt(2n) / M`, which keeps `|Q(x)|` below about
+  // `M sqrt(n / 2)` across the interval, then sets up the first member.
+  #[inline]
+  pub(super) fn choose(
+    n: &'a BigInt,
+    base: &'a [FactorBasePrime],
+    half_width: i64,
+    rng: &mut SplitMix64,
+    used: &mut HashSet<Vec<usize>>,
+  ) -> Option<Self> {
+    let target = &(n << 1).sqrt().ok()? / half_width as u32;
+    let target_bits = target.bit_length();
+    let count = ((target_bits + A_FACTOR_BITS / 2) / A_FACTOR_BITS).max(1);
+    let ideal = 2f64.powf(target_bits as f64 / count as f64);
+
+    let mut pool: Vec<usize> = (1..base.len())
+      .filter(|idx| {
+        let prime = base[*idx].prime as f64;
+        prime >= ideal / 2.0 && prime <= ideal * 2.0
+      })
+      .collect();
+    if pool.len() < 2 * count {
+      pool = ((base.len() / 4).max(1)..base.len()).collect();
+    }
+
+    for _ in 0..A_ATTEMPTS {
+      let mut factors = vec![];
+      while factors.len() + 1 < count {
+        let idx = pool[rng.next_u64() as usize % pool.len()];
+        if !factors.contains(&idx) {
+          factors.push(idx);
+        }
+      }
+
+      let partial = factors
+        .iter()
+        .fold(BigInt::one(), |product, idx| &product * base[*idx].prime);
+      let last = match count {
+        1 => pool[rng.next_u64() as usize % pool.len()],
+        _ => closest_prime(base, &(&target / &partial), &factors)?,
+      };
+      factors.push(last);
+      factors.sort_unstable();
+
+      if used.insert(factors.clone()) {
+        return Some(Self::new(n, base, factors));
+      }
+    }
+
+    None
+  }
+
+  #[inline]
+  fn new(
+    n: &'a BigInt,
+    base: &'a [FactorBasePrime],
+    a_factors: Vec<usize>,
+  ) -> Self {
+    let a = a_factors
+      .iter()
+      .fold(BigInt::one(), |product, idx| &product * base[*idx].prime);
+
+    // `B_l = (A / q_l) * (t_l * (A / q_l)^-1 mod q_l)`, so that `B_l^2 = n`
+    // modulo `q_l` and `B_l = 0` modulo the other factors of `A`.
+    let b_terms: Vec<BigInt> = a_factors
+      .iter()
+      .map(|idx| {
+        let FactorBasePrime { prime, sqrt_n, .. } = base[*idx];
+        let cofactor = &a / prime;
+        let inverse = inverse_mod((&cofactor % prime) as u64, prime as u64);
+        let mut gamma = sqrt_n as u64 * inverse % prime as u64;
+        if gamma > prime as u64 / 2 {
+          gamma = prime as u64 - gamma;
+        }
+
+        &cofactor * gamma as u32
+      })
+      .collect();
+    let b = b_terms.iter().fold(BigInt::zero(), |sum, term| &sum + term);
+
+    let a_inverses: Vec<u32> = base
+      .iter()
+      .enumerate()
+      .map(|(idx, prime)| match idx == 0 || a_factors.contains(&idx) {
+        true => 0,
+        false => {
+          let prime = prime.prime as u64;
+          inverse_mod((&a % prime as u32) as u64, prime) as u32
+        }
+      })
+      .collect();
+
+    let root_steps = b_terms
+      .iter()
+      .map(|term| {
+        base
+          .iter()
+          .zip(&a_inverses)
+          .map(|(prime, inverse)| {
+            let prime = prime.prime as u64;
+            (2 * (term % prime as u32) as u64 % prime * *inverse as u64 % prime)
+              as u32
+          })
+          .collect()
+      })
+      .collect();
+
+    let roots = base
+      .iter()
+      .zip(&a_inverses)
+      .map(|(prime, inverse)| {
+        let FactorBasePrime { prime, sqrt_n, .. } = *prime;
+        let (prime, sqrt_n, inverse) =
+          (prime as u64, sqrt_n as u64, *inverse as u64);
+        let b = (&b % prime as u32) as u64;
+
+        (
+          ((sqrt_n + prime - b) % prime * inverse % prime) as u32,
+          ((2 * prime - sqrt_n - b) % prime * inverse % prime) as u32,
+        )
+      })
+      .collect();
+
+    Self {
+      n,
+      base,
+      c: &(&b.square() - n) / &a,
+      a,
+      a_factors,
+      b,
+      b_terms,
+      a_inverses,
+      root_steps,
+      roots,
+    }
+  }
+
+  // The number of members, fixing the sign of the last `B_l`.
+  #[inline(always)]
+  pub(super) fn count(&self) -> usize {
+    1 << (self.b_terms.len() - 1)
+  }
+
+  // Moves from member `idx - 1` to member `idx` in Gray-code order, which
+  // flips the sign of a single `B_v`.
+  #[inline]
+  pub(super) fn advance(&mut self, idx: usize) {
+    let flipped = idx.trailing_zeros() as usize;
+    let negated = (idx ^ (idx >> 1)) & (1 << flipped) != 0;
+    let doubled = &self.b_terms[flipped] << 1;
+
+    self.b = match negated {
+      true => &self.b - &doubled,
+      false => &self.b + &doubled,
+    };
+    self.c = &(&self.b.square() - self.n) / &self.a;
+
+    // The roots are `A^-1 (±t - B)`, so they move against `B`.
+    for ((roots, prime), step) in self
+      .roots
+      .iter_mut()
+      .zip(self.base)
+      .zip(&self.root_steps[flipped])
+    {
+      let (prime, step) = (prime.prime, *step);
+      let shift = |root: u32| match negated {
+        true => ((root as u64 + step as u64) % prime as u64) as u32,
+        false => ((root as u64 + (prime - step) as u64) % prime as u64) as u32,
+      };
+      *roots = (shift(roots.0), shift(roots.1));
+    }
+  }
+
+  // Adds `log2(p)` at every position in `[-M, M)` where `p` divides `Q(x)`.
+  #[inline]
+  pub(super) fn sieve(&self, sieve: &mut [u8], half_width: i64) {
+    sieve.fill(0);
+
+    for ((prime, roots), inverse) in
+      self.base.iter().zip(&self.roots).zip(&self.a_inverses)
+    {
+      if *inverse == 0 {
+        continue;
+      }
+
+      let step = prime.prime as usize;
+      let offset = (half_width % prime.prime as i64) as usize;
+      for root in [roots.0, roots.1] {
+        let start = (root as usize + offset) % step;
+        for position in (start..sieve.len()).step_by(step) {
+          sieve[position] = sieve[position].wrapping_add(prime.log);
+        }
+      }
+    }
+  }
+
+  // Trial-divides `Q(x)` over the factor base, accepting it if what is left
+  // is one or a single prime below `large_prime_bound`.
+  #[inline]
+  pub(super) fn relation(
+    &self,
+    x: i64,
+    large_prime_bound: u64,
+  ) -> Option<Relation> {
+    let x_big = BigInt::from(x);
+    let y = &(&self.a * &x_big) + &self.b;
+    let value = &(&(&(&self.a * &x_big) + &(&self.b << 1)) * &x_big) + &self.c;
+
+    if value.is_zero() {
+      return None;
+    }
+
+    let mut exponents = vec![];
+    if value.sign.is_negative() {
+      exponents.push((0, 1));
+    }
+
+    let mut rest = value.abs();
+    for (idx, (prime, roots)) in self.base.iter().zip(&self.roots).enumerate() {
+      let p = prime.prime;
+      let residue = x.rem_euclid(p as i64) as u32;
+      let sieved = self.a_inverses[idx] != 0;
+
+      let mut exponent = match self.a_factors.contains(&idx) {
+        true => 1,
+        false => 0,
+      };
+      if !sieved || residue == roots.0 || residue == roots.1 {
+        while &rest % p == 0 {
+          rest /= p;
+          exponent += 1;
+        }
+      }
+
+      if exponent > 0 {
+        exponents.push((idx + 1, exponent));
+      }
+    }
+
+    let cofactor = match rest.magnitude() <= 2 {
+      true => super::to_u64(&rest),
+      false => return None,
+    };
+    if cofactor >= large_prime_bound {
+      return None;
+    }
+
+    Some(Relation {
+      y,
+      exponents,
+      cofactor: BigInt::from(cofactor),
+    })
+  }
+}
+
+// The factor-base prime nearest to `target` that is odd and not already
+// among `taken`.
+#[inline]
+fn closest_prime(
+  base: &[FactorBasePrime],
+  target: &BigInt,
+  taken: &[usize],
+) -> Option<usize> {
+  let target = match target.magnitude() {
+    1 => target.digits[0] as i64,
+    _ => return None,
+  };
+
+  (1..base.len())
+    .filter(|idx| !taken.contains(idx))
+    .min_by_key(|idx| (base[*idx].prime as i64 - target).abs())
+}
@@ -0,0 +1,54 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::fraction::Fraction;
+
+impl Add for &Fraction {
+  type Output = Fraction;
+
+  #[inline(always)]
+  fn add(self, rhs: Self) -> Self::Output {
+    Fraction::new(
+      &(&self.numerator * &rhs.denominator)
+        + &(&rhs.numerator * &self.denominator),
+      &self.denominator * &rhs.denominator,
+    )
+  }
+}
+
+impl Sub for &Fraction {
+  type Output = Fraction;
+
+  #[inline(always)]
+  fn sub(self, rhs: Self) -> Self::Output {
+    Fraction::new(
+      &(&self.numerator * &rhs.denominator)
+        - &(&rhs.numerator * &self.denominator),
+      &self.denominator * &rhs.denominator,
+    )
+  }
+}
+
+impl Mul for &Fraction {
+  type Output = Fraction;
+
+  #[inline(always)]
+  fn mul(self, rhs: Self) -> Self::Output {
+    Fraction::new(
+      &self.numerator * &rhs.numerator,
+      &self.denominator * &rhs.denominator,
+    )
+  }
+}
+
+impl Div for &Fraction {
+  type Output = Fraction;
+
+  /// Panics if `rhs` is zero.
+  #[inline(always)]
+  fn div(self, rhs: Self) -> Self::Output {
+    Fraction::new(
+      &self.numerator * &rhs.denominator,
+      &self.denominator * &rhs.numerator,
+    )
+  }
+}
@@ -1,6 +1,69 @@
+mod arithmetic;
+mod numbers;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
 use crate::bigint::BigInt;
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct Fraction {
   numerator: BigInt,
   denominator: BigInt,
 }
+
+impl Fraction {
+  /// Builds `numerator / denominator` in lowest terms with a positive
+  /// denominator. Panics if `denominator` is zero.
+  #[inline]
+  pub fn new(numerator: BigInt, denominator: BigInt) -> Self {
+    if denominator.is_zero() {
+      panic!("Attempt to divide by zero");
+    }
+
+    let mut divisor = numerator.gcd(&denominator);
+    if denominator.sign.is_negative() {
+      divisor.negate();
+    }
+
+    Self {
+      numerator: &numerator / &divisor,
+      denominator: &denominator / &divisor,
+    }
+  }
+
+  #[inline(always)]
+  pub fn numerator(&self) -> &BigInt {
+    &self.numerator
+  }
+
+  #[inline(always)]
+  pub fn denominator(&self) -> &BigInt {
+    &self.denominator
+  }
+}
+
+impl From<BigInt> for Fraction {
+  #[inline(always)]
+  fn from(value: BigInt) -> Self {
+    Self {
+      numerator: value,
+      denominator: BigInt::one(),
+    }
+  }
+}
+
+impl Display for Fraction {
+  #[inline(always)]
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+    if self.numerator.sign.is_negative() {
+      write!(formatter, "-")?;
+    }
+
+    match self.denominator == BigInt::one() {
+      true => write!(formatter, "{}", self.numerator.abs()),
+      false => {
+        write!(formatter, "{}/{}", self.numerator.abs(), self.denominator)
+      }
+    }
+  }
+}
@@ -0,0 +1,230 @@
+use crate::{
+  bigint::{impls::factorial::factorial, BigInt},
+  fraction::Fraction,
+  sieve::primes_up_to,
+};
+
+// Even indices from which a single `B_n` comes from the zeta function rather
+// than the `O(n^2)` tangent triangle.
+const BERNOULLI_ZETA_CROSSOVER: u64 = 34;
+
+// `log2(2 pi)`, for sizing the working precision.
+const LOG2_TAU: f64 = 2.651496129472319;
+
+impl Fraction {
+  /// The Bernoulli number `B_n`, with the convention `B_1 = -1/2`. Every odd
+  /// index past one gives zero. Small even indices read the tangent triangle
+  /// described on [`Fraction::bernoulli_numbers`]; larger ones use
+  /// `|B_n| = 2 n! zeta(n) / (2 pi)^n`, with `pi` summed by binary splitting
+  /// and the denominator fixed exactly by the von Staudt-Clausen theorem, so
+  /// that only enough precision to round the numerator is ever needed.
+  #[inline]
+  pub fn bernoulli(n: u64) -> Self {
+    match n {
+      0 => Self::from(BigInt::one()),
+      1 => Self::new(BigInt::negative_one(), BigInt::from(2u32)),
+      _ if n & 1 == 1 => Self::from(BigInt::zero()),
+      _ if n >= BERNOULLI_ZETA_CROSSOVER => Self::bernoulli_by_zeta(n),
+      _ => {
+        let half = n / 2;
+        bernoulli_from_tangent(half, tangent_numbers(half).pop().unwrap())
+      }
+    }
+  }
+
+  /// The Bernoulli numbers `B_0` through `B_n`, with `B_1 = -1/2`. The even
+  /// ones come from the tangent numbers, built in `O(n^2)` word-sized
+  /// multiplications by Seidel's boustrophedon triangle, through
+  /// `B_2k = (-1)^(k - 1) 2k T_k / (4^k (4^k - 1))`. The triangle yields every
+  /// number up to `n` at once, so for a whole table this beats calling
+  /// [`Fraction::bernoulli`] per index.
+  #[inline]
+  pub fn bernoulli_numbers(n: u64) -> Vec<Self> {
+    let tangents = tangent_numbers(n / 2);
+
+    (0..=n)
+      .map(|index| match index {
+        0 | 1 => Self::bernoulli(index),
+        _ if index & 1 == 1 => Self::from(BigInt::zero()),
+        _ => {
+          let half = index / 2;
+          bernoulli_from_tangent(half, tangents[half as usize - 1].clone())
+        }
+      })
+      .collect()
+  }
+
+  /// The harmonic number `H_n = 1 + 1/2 + ... + 1/n`, summed by binary
+  /// splitting so the work lands in balanced multiplications and a single
+  /// final reduction.
+  #[inline]
+  pub fn harmonic(n: u64) -> Self {
+    if n == 0 {
+      return Self::from(BigInt::zero());
+    }
+
+    let (numerator, denominator) = harmonic_split(1, n + 1);
+    Self::new(numerator, denominator)
+  }
+
+  // `B_n` for even `n >= 4` from `|B_n| = 2 n! zeta(n) / (2 pi)^n`. The
+  // denominator is the product of the primes `p` with `p - 1` dividing `n`,
+  // so the numerator is an integer and a fixed-point approximation with a
+  // few dozen guard bits past its length rounds to it exactly.
+  #[inline]
+  pub(crate) fn bernoulli_by_zeta(n: u64) -> Self {
+    let primes: Vec<u64> = primes_up_to(n + 1).collect();
+    let denominator = primes
+      .iter()
+      .filter(|&&prime| n.is_multiple_of(prime - 1))
+      .fold(BigInt::one(), |product, &prime| &product * &BigInt::from(prime));
+    let scaled = &factorial(n, &primes) * &denominator;
+
+    // `zeta(n)` lies in `(1, 2)`, so this bounds the numerator's length.
+    let numerator_bits = (scaled.bit_length() + 1)
+      .saturating_sub((n as f64 * LOG2_TAU) as usize)
+      + 1;
+    let precision =
+      numerator_bits + 2 * (u64::BITS - n.leading_zeros()) as usize + 32;
+
+    // `2^precision / zeta(n)` from the Euler product, cut off once the
+    // omitted factors move it by less than a unit.
+    let limit = (precision as f64 / (n - 1) as f64).exp2().ceil() as u64;
+    let mut inverse_zeta = &BigInt::one() << precision;
+    for prime in primes_up_to(limit) {
+      let factor = BigInt::from(prime).pow(n);
+      inverse_zeta = &inverse_zeta - &(&inverse_zeta / &factor);
+    }
+
+    let tau = &fixed_pi(precision) << 1;
+    let tau_power = fixed_pow(&tau, n, precision);
+
+    // Both factors of the divisor carry a `2^precision` scale, which the
+    // shift cancels before rounding to nearest.
+    let divisor = &inverse_zeta * &tau_power;
+    let mut numerator = &(&(&scaled << (2 * precision + 2)) + &divisor)
+      / &(&divisor << 1);
+    if n & 3 == 0 {
+      numerator.negate();
+    }
+
+    Self::new(numerator, denominator)
+  }
+}
+
+// `pi * 2^precision`, rounded down, from the Chudnovsky series
+// `1 / pi = 12 sum (-1)^k (6k)! (13591409 + 545140134k)
+//   / ((3k)! k!^3 640320^(3k + 3/2))`, each term of which adds about 47 bits.
+#[inline]
+fn fixed_pi(precision: usize) -> BigInt {
+  let terms = precision as u64 / 47 + 2;
+  let (_, q, t) = chudnovsky_split(0, terms);
+  let root = (&BigInt::from(10005u32) << (2 * precision))
+    .sqrt()
+    .expect("the radicand is positive");
+
+  &(&(&root * &BigInt::from(426880u32)) * &q) / &t
+}
+
+// The binary-splitting triple `(P, Q, T)` over the series terms in
+// `[low, high)`.
+#[inline]
+fn chudnovsky_split(low: u64, high: u64) -> (BigInt, BigInt, BigInt) {
+  if high - low == 1 {
+    if low == 0 {
+      return (BigInt::one(), BigInt::one(), BigInt::from(13591409u32));
+    }
+
+    let k = BigInt::from(low);
+    let p = &(&BigInt::from(6 * low - 5) * &BigInt::from(2 * low - 1))
+      * &BigInt::from(6 * low - 1);
+    let q = &(&k * &k.square()) * &BigInt::from(10939058860032000u64);
+    let mut t = &p * &BigInt::from(13591409 + 545140134 * low);
+    if low & 1 == 1 {
+      t.negate();
+    }
+
+    return (p, q, t);
+  }
+
+  let middle = low + (high - low) / 2;
+  let (left_p, left_q, left_t) = chudnovsky_split(low, middle);
+  let (right_p, right_q, right_t) = chudnovsky_split(middle, high);
+
+  (
+    &left_p * &right_p,
+    &left_q * &right_q,
+    &(&left_t * &right_q) + &(&left_p * &right_t),
+  )
+}
+
+// `base^exponent` for a fixed-point `base` scaled by `2^precision`,
+// truncating back to that scale after every product.
+#[inline]
+fn fixed_pow(base: &BigInt, exponent: u64, precision: usize) -> BigInt {
+  let mut result = &BigInt::one() << precision;
+  for bit in (0..u64::BITS - exponent.leading_zeros()).rev() {
+    result = &result.square() >> precision;
+    if (exponent >> bit) & 1 == 1 {
+      result = &(&result * base) >> precision;
+    }
+  }
+
+  result
+}
+
+// `B_2k` from the tangent number `T_k`.
+#[inline]
+fn bernoulli_from_tangent(k: u64, tangent: BigInt) -> Fraction {
+  let power = &BigInt::one() << (2 * k as usize);
+  let mut numerator = &tangent * &BigInt::from(2 * k);
+  if k & 1 == 0 {
+    numerator.negate();
+  }
+
+  Fraction::new(numerator, &power * &(&power - 1))
+}
+
+// The tangent numbers `T_1` through `T_count`, the coefficients of
+// `tan(x) = sum T_k x^(2k - 1) / (2k - 1)!`.
+#[inline]
+fn tangent_numbers(count: u64) -> Vec<BigInt> {
+  let count = count as usize;
+  let mut tangents = Vec::with_capacity(count);
+  if count == 0 {
+    return tangents;
+  }
+
+  tangents.push(BigInt::one());
+  for k in 1..count {
+    let next = &tangents[k - 1] * k as u32;
+    tangents.push(next);
+  }
+
+  for k in 1..count {
+    for j in k..count {
+      tangents[j] = &(&tangents[j - 1] * (j - k) as u32)
+        + &(&tangents[j] * (j - k + 2) as u32);
+    }
+  }
+
+  tangents
+}
+
+// `sum 1/k` over `k` in `[low, high)` as an unreduced `(p, q)`.
+#[inline]
+fn harmonic_split(low: u64, high: u64) -> (BigInt, BigInt) {
+  if high - low == 1 {
+    return (BigInt::one(), BigInt::from(low));
+  }
+
+  let middle = low + (high - low) / 2;
+  let (left_numerator, left_denominator) = harmonic_split(low, middle);
+  let (right_numerator, right_denominator) = harmonic_split(middle, high);
+
+  (
+    &(&left_numerator * &right_denominator)
+      + &(&right_numerator * &left_denominator),
+    &left_denominator * &right_denominator,
+  )
+}
@@ -1,5 +1,8 @@
 pub mod bigint;
+pub mod combinatorics;
+pub mod factor;
 pub mod fraction;
+pub mod sieve;
 pub mod traits;
 pub mod utils;
 
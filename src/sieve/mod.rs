@@ -0,0 +1,178 @@
+use crate::bigint::BigInt;
+
+// Odd values covered by one segment, sized so the flags stay in L1 cache.
+const SEGMENT: usize = 1 << 15;
+
+/// The primes up to 251, the table used for trial division throughout the
+/// crate.
+pub const SMALL_PRIMES: [u32; 54] = [
+  2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+  73, 79, 83, 89, 97, 101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151,
+  157, 163, 167, 173, 179, 181, 191, 193, 197, 199, 211, 223, 227, 229, 233,
+  239, 241, 251,
+];
+
+/// The primes up to and including `limit` in increasing order, from a
+/// segmented sieve of Eratosthenes over the odd numbers. Memory use is
+/// `O(sqrt(limit))` however far the iterator is driven.
+#[inline]
+pub fn primes_up_to(limit: u64) -> PrimesUpTo {
+  let base: Vec<u64> = match limit.isqrt() {
+    root if root < 3 => vec![],
+    root => simple_sieve(root).into_iter().skip(1).collect(),
+  };
+
+  PrimesUpTo {
+    limit,
+    next_multiples: base.iter().map(|prime| prime * prime).collect(),
+    base,
+    low: 1,
+    segment: vec![],
+    position: 0,
+    yielded_two: false,
+  }
+}
+
+/// The number of primes up to and including `n`, by Lucy Hedgehog's
+/// variant of the Legendre-Meissel method in `O(n^(3/4))` time and
+/// `O(sqrt(n))` memory.
+#[inline]
+pub fn prime_pi(n: u64) -> u64 {
+  if n < 2 {
+    return 0;
+  }
+
+  // `small[v]` and `large[i]` count the values in `[2, v]` and `[2, n / i]`
+  // not yet struck out by a prime below the current one.
+  let root = n.isqrt() as usize;
+  let mut small: Vec<u64> =
+    (0..=root as u64).map(|v| v.saturating_sub(1)).collect();
+  let mut large: Vec<u64> = (0..=root as u64)
+    .map(|i| (n / i.max(1)).saturating_sub(1))
+    .collect();
+
+  for prime in 2..=root {
+    if small[prime] == small[prime - 1] {
+      continue;
+    }
+
+    let below = small[prime - 1];
+    let square = (prime * prime) as u64;
+
+    let last = root.min((n / square) as usize);
+    for i in 1..=last {
+      let quotient = i * prime;
+      let struck = match quotient <= root {
+        true => large[quotient],
+        false => small[(n / quotient as u64) as usize],
+      };
+      large[i] -= struck - below;
+    }
+
+    for v in (square as usize..=root).rev() {
+      small[v] -= small[v / prime] - below;
+    }
+  }
+
+  large[1]
+}
+
+/// The product of the primes up to and including `n`.
+#[inline(always)]
+pub fn primorial(n: u64) -> BigInt {
+  let primes: Vec<u64> = primes_up_to(n).collect();
+  BigInt::product(&primes)
+}
+
+/// Iterator over the primes up to a bound; see [`primes_up_to`].
+pub struct PrimesUpTo {
+  limit: u64,
+  // The odd primes up to `sqrt(limit)` and, for each, its next odd multiple
+  // at or past the current segment.
+  base: Vec<u64>,
+  next_multiples: Vec<u64>,
+  // The segment flags composites among `low, low + 2, ...`.
+  low: u64,
+  segment: Vec<bool>,
+  position: usize,
+  yielded_two: bool,
+}
+
+impl PrimesUpTo {
+  // Moves to the segment after the current one, or reports the end.
+  #[inline]
+  fn advance(&mut self) -> bool {
+    if !self.segment.is_empty() {
+      self.low += 2 * SEGMENT as u64;
+    }
+    if self.low > self.limit {
+      return false;
+    }
+
+    let len = SEGMENT.min(((self.limit - self.low) / 2) as usize + 1);
+    let high = self.low + 2 * len as u64;
+
+    self.segment.clear();
+    self.segment.resize(len, false);
+    self.position = 0;
+    if self.low == 1 {
+      self.segment[0] = true;
+    }
+
+    for (prime, next) in self.base.iter().zip(&mut self.next_multiples) {
+      while *next < high {
+        self.segment[((*next - self.low) / 2) as usize] = true;
+        *next += 2 * prime;
+      }
+    }
+
+    true
+  }
+}
+
+impl Iterator for PrimesUpTo {
+  type Item = u64;
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    if !self.yielded_two {
+      self.yielded_two = true;
+      if self.limit >= 2 {
+        return Some(2);
+      }
+    }
+
+    loop {
+      while self.position < self.segment.len() {
+        let idx = self.position;
+        self.position += 1;
+        if !self.segment[idx] {
+          return Some(self.low + 2 * idx as u64);
+        }
+      }
+
+      if !self.advance() {
+        return None;
+      }
+    }
+  }
+}
+
+// An unsegmented sieve for the base primes.
+#[inline]
+fn simple_sieve(limit: u64) -> Vec<u64> {
+  let mut composite = vec![false; limit as usize + 1];
+  let mut primes = vec![];
+
+  for value in 2..=limit as usize {
+    if composite[value] {
+      continue;
+    }
+    primes.push(value as u64);
+    for multiple in (value * value..=limit as usize).step_by(value) {
+      composite[multiple] = true;
+    }
+  }
+
+  primes
+}
@@ -0,0 +1,156 @@
+use crate::{
+  bigint::BigInt,
+  combinatorics::{
+    bell, binomial, catalan, double_factorial, falling_factorial, multinomial,
+    partitions, rising_factorial, stirling_first, stirling_second,
+  },
+};
+
+fn num_product(values: impl Iterator<Item = u64>) -> num::BigUint {
+  values.fold(num::BigUint::from(1u32), |product, value| product * value)
+}
+
+fn to_biguint(value: &BigInt) -> num::BigUint {
+  num::BigUint::from_slice(&value.digits)
+}
+
+#[test]
+fn test_binomial() {
+  // Pascal's triangle.
+  let mut row = vec![num::BigUint::from(1u32)];
+  for n in 0..300u64 {
+    for k in 0..=n + 1 {
+      let expected = row.get(k as usize).cloned().unwrap_or_default();
+      assert_eq!(to_biguint(&binomial(n, k)), expected, "C({n}, {k})");
+    }
+
+    let mut next = vec![num::BigUint::from(1u32)];
+    next.extend(row.windows(2).map(|pair| &pair[0] + &pair[1]));
+    next.push(num::BigUint::from(1u32));
+    row = next;
+  }
+
+  let (n, k) = (200000, 70000);
+  let expected = num_product(n - k + 1..=n) / num_product(1..=k);
+  assert_eq!(to_biguint(&binomial(n, k)), expected);
+}
+
+#[test]
+fn test_multinomial() {
+  assert_eq!(multinomial(&[]), BigInt::one());
+  assert_eq!(multinomial(&[0, 0]), BigInt::one());
+  assert_eq!(multinomial(&[2, 1, 1]), BigInt::from(12u64));
+
+  // All ones give `n!`, whose prime powers outgrow a word.
+  let ones = [1; 500];
+  assert_eq!(to_biguint(&multinomial(&ones)), num_product(1..=500));
+
+  let ks = [120, 75, 300, 7];
+  let expected = ks.iter().fold(num_product(1..=502), |quotient, k| {
+    quotient / num_product(1..=*k)
+  });
+  assert_eq!(to_biguint(&multinomial(&ks)), expected);
+}
+
+#[test]
+fn test_catalan() {
+  let expected = [1u64, 1, 2, 5, 14, 42, 132, 429, 1430, 4862, 16796];
+  for (n, value) in expected.iter().enumerate() {
+    assert_eq!(catalan(n as u64), BigInt::from(*value), "{n}");
+  }
+
+  for n in [100, 1000] {
+    let expected =
+      to_biguint(&binomial(2 * n, n)) - to_biguint(&binomial(2 * n, n + 1));
+    assert_eq!(to_biguint(&catalan(n)), expected, "{n}");
+  }
+}
+
+#[test]
+fn test_stirling() {
+  // Recurrences for both kinds, built row by row.
+  let size = 60;
+  let mut first = vec![vec![num::BigUint::default(); size + 1]; size + 1];
+  let mut second = first.clone();
+  first[0][0] = 1u32.into();
+  second[0][0] = 1u32.into();
+  for n in 1..=size {
+    for k in 1..=n {
+      first[n][k] = &first[n - 1][k - 1] + &first[n - 1][k] * (n as u64 - 1);
+      second[n][k] = &second[n - 1][k - 1] + &second[n - 1][k] * k as u64;
+    }
+  }
+
+  for n in 0..=size {
+    for k in 0..=size {
+      let (n64, k64) = (n as u64, k as u64);
+      assert_eq!(
+        to_biguint(&stirling_first(n64, k64)),
+        first[n][k],
+        "{n} {k}"
+      );
+      assert_eq!(
+        to_biguint(&stirling_second(n64, k64)),
+        second[n][k],
+        "{n} {k}"
+      );
+    }
+  }
+
+  // Every permutation has some number of cycles.
+  let total =
+    (0..=200).fold(BigInt::zero(), |sum, k| &sum + &stirling_first(200, k));
+  assert_eq!(to_biguint(&total), num_product(1..=200));
+}
+
+#[test]
+fn test_bell() {
+  let expected = [1u64, 1, 2, 5, 15, 52, 203, 877, 4140, 21147, 115975];
+  for (n, value) in expected.iter().enumerate() {
+    assert_eq!(bell(n as u64), BigInt::from(*value), "{n}");
+  }
+
+  let total =
+    (0..=150).fold(BigInt::zero(), |sum, k| &sum + &stirling_second(150, k));
+  assert_eq!(bell(150), total);
+}
+
+#[test]
+fn test_partitions() {
+  let expected = [1u64, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42];
+  for (n, value) in expected.iter().enumerate() {
+    assert_eq!(partitions(n as u64), BigInt::from(*value), "{n}");
+  }
+
+  assert_eq!(partitions(100), BigInt::from(190569292u64));
+  assert_eq!(
+    partitions(1000).to_string(),
+    "24061467864032622473692149727991"
+  );
+}
+
+#[test]
+fn test_factorial_variants() {
+  for n in 0..200u64 {
+    let expected = num_product((1..=n).rev().step_by(2));
+    assert_eq!(to_biguint(&double_factorial(n)), expected, "{n}!!");
+  }
+
+  for x in 0..40u64 {
+    for n in 0..40u64 {
+      let rising = num_product(x..x + n);
+      assert_eq!(to_biguint(&rising_factorial(x, n)), rising, "{x} {n}");
+
+      let falling = match n > x {
+        true => num::BigUint::default(),
+        false => num_product(x - n + 1..=x),
+      };
+      assert_eq!(to_biguint(&falling_factorial(x, n)), falling, "{x} {n}");
+    }
+  }
+
+  assert_eq!(
+    to_biguint(&falling_factorial(100000, 30000)),
+    num_product(70001..=100000)
+  );
+}
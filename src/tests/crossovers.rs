@@ -0,0 +1,136 @@
+// Timings behind the crossover constants in `src/bigint/mod.rs`. They are
+// ignored by default; run them with
+// `cargo test --release bench_ -- --ignored --nocapture --test-threads 1`.
+use std::time::{Duration, Instant};
+
+use super::helpers::XorShift;
+use crate::{
+  bigint::{impls::gcd::GcdCrossovers, BigInt},
+  utils::{
+    digital_multiply_dispatch_u32, karatsuba_mul, ntt_mul, toom_3_mul,
+    toom_4_mul, MultiplyCrossovers,
+  },
+};
+
+// The fastest of many short batches of each candidate, taken in turn so
+// that a slow stretch of the machine hits every candidate alike.
+fn best_times<T, const N: usize>(
+  mut candidates: [&mut dyn FnMut() -> T; N],
+) -> [Duration; N] {
+  let mut best = [Duration::MAX; N];
+  for _ in 0..15 {
+    for (candidate, best) in candidates.iter_mut().zip(&mut best) {
+      let timer = Instant::now();
+      let mut runs = 0;
+      while timer.elapsed() < Duration::from_millis(20) {
+        std::hint::black_box(candidate());
+        runs += 1;
+      }
+      *best = (*best).min(timer.elapsed() / runs);
+    }
+  }
+
+  best
+}
+
+#[test]
+#[ignore = "benchmark"]
+fn bench_toom_crossovers() {
+  let mut rng = XorShift::new(0xc055);
+  let crossovers = MultiplyCrossovers {
+    ntt: usize::MAX,
+    ..BigInt::multiply_crossovers()
+  };
+
+  for magnitude in [120, 160, 200, 240, 280, 320, 400, 500, 600, 800, 1000] {
+    let (lhs, rhs) = (rng.digits(magnitude), rng.digits(magnitude));
+
+    let [karatsuba, toom_3, toom_4] = best_times([
+      &mut || karatsuba_mul(&lhs, &rhs, crossovers.karatsuba),
+      &mut || toom_3_mul(&lhs, &rhs, crossovers),
+      &mut || toom_4_mul(&lhs, &rhs, crossovers),
+    ]);
+
+    println!(
+      "{magnitude:>5}: karatsuba {karatsuba:>9.1?} toom-3 {toom_3:>9.1?} \
+       toom-4 {toom_4:>9.1?}"
+    );
+  }
+}
+
+#[test]
+#[ignore = "benchmark"]
+fn bench_ntt_crossover() {
+  let mut rng = XorShift::new(0x2771);
+  let crossovers = MultiplyCrossovers {
+    ntt: usize::MAX,
+    ..BigInt::multiply_crossovers()
+  };
+
+  for magnitude in [240, 280, 320, 360, 400, 500, 600, 800, 1000, 1500] {
+    let (lhs, rhs) = (rng.digits(magnitude), rng.digits(magnitude));
+
+    let [toom, ntt] = best_times([
+      &mut || digital_multiply_dispatch_u32(&lhs, &rhs, crossovers),
+      &mut || ntt_mul(&lhs, &rhs),
+    ]);
+
+    println!("{magnitude:>5}: toom {toom:>9.1?} ntt {ntt:>9.1?}");
+  }
+}
+
+#[test]
+#[ignore = "benchmark"]
+fn bench_half_gcd_base_crossover() {
+  let mut rng = XorShift::new(0x6cd);
+
+  for magnitude in [2000, 4000, 8000] {
+    let (lhs, rhs) = (rng.bigint(magnitude), rng.bigint(magnitude));
+
+    // A single half-GCD step on the full operands, then Lehmer steps.
+    let with_base = |base| GcdCrossovers {
+      half_gcd: magnitude,
+      base,
+    };
+
+    let [base_16, base_32, base_64, base_128, base_256] = best_times([
+      &mut || lhs.gcd_with_crossovers(&rhs, with_base(16)),
+      &mut || lhs.gcd_with_crossovers(&rhs, with_base(32)),
+      &mut || lhs.gcd_with_crossovers(&rhs, with_base(64)),
+      &mut || lhs.gcd_with_crossovers(&rhs, with_base(128)),
+      &mut || lhs.gcd_with_crossovers(&rhs, with_base(256)),
+    ]);
+
+    println!(
+      "{magnitude:>5}: 16 {base_16:>9.1?} 32 {base_32:>9.1?} \
+       64 {base_64:>9.1?} 128 {base_128:>9.1?} 256 {base_256:>9.1?}"
+    );
+  }
+}
+
+#[test]
+#[ignore = "benchmark"]
+fn bench_half_gcd_crossover() {
+  let mut rng = XorShift::new(0x4a1f);
+
+  for magnitude in [1000, 1250, 1500, 1750, 2000, 3000, 4000, 8000, 12000] {
+    let (lhs, rhs) = (rng.bigint(magnitude), rng.bigint(magnitude));
+    let lehmer = GcdCrossovers {
+      half_gcd: usize::MAX,
+      ..BigInt::gcd_crossovers()
+    };
+    // Half-GCD only for the first half of the bits, which is the choice the
+    // crossover makes at this size.
+    let half_gcd = GcdCrossovers {
+      half_gcd: magnitude,
+      ..BigInt::gcd_crossovers()
+    };
+
+    let [lehmer, half_gcd] = best_times([
+      &mut || lhs.gcd_with_crossovers(&rhs, lehmer),
+      &mut || lhs.gcd_with_crossovers(&rhs, half_gcd),
+    ]);
+
+    println!("{magnitude:>5}: lehmer {lehmer:>9.1?} half-gcd {half_gcd:>9.1?}");
+  }
+}
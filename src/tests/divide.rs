@@ -0,0 +1,229 @@
+use super::helpers::{to_num, XorShift};
+use crate::{
+  bigint::{BigInt, Reciprocal},
+  utils::{digital_divide_with_rem_u32, Sign},
+};
+
+fn assert_rounding_matches_num(dividend: &BigInt, divisor: &BigInt) {
+  let (left, right) = (to_num(dividend), to_num(divisor));
+
+  assert_eq!(
+    to_num(&dividend.div_floor(divisor)),
+    num::Integer::div_floor(&left, &right)
+  );
+  assert_eq!(
+    to_num(&dividend.rem_floor(divisor)),
+    num::Integer::mod_floor(&left, &right)
+  );
+  assert_eq!(
+    to_num(&dividend.div_ceil(divisor)),
+    num::Integer::div_ceil(&left, &right)
+  );
+}
+
+#[test]
+fn test_digital_long_division() {
+  let (quotient, remainder) =
+    digital_divide_with_rem_u32(&[0, 0, 1], &[u32::MAX, 1]);
+  assert_eq!(quotient, vec![2147483648]);
+  assert_eq!(remainder, vec![2147483648]);
+
+  let (quotient, remainder) = digital_divide_with_rem_u32(&[7, 3], &[7, 3]);
+  assert_eq!(quotient, vec![1]);
+  assert_eq!(remainder, vec![0]);
+
+  let (quotient, remainder) = digital_divide_with_rem_u32(&[7, 3], &[8, 3, 0]);
+  assert_eq!(quotient, vec![0]);
+  assert_eq!(remainder, vec![7, 3]);
+
+  // Forces the add-back step: the first quotient estimate overshoots by one.
+  let (quotient, remainder) = digital_divide_with_rem_u32(
+    &[0xfffffffe, 1, 0xfffffffe, u32::MAX, 0x80000000],
+    &[0xfffffffe, u32::MAX, u32::MAX],
+  );
+  assert_eq!(quotient, vec![u32::MAX, 0x80000000]);
+  assert_eq!(remainder, vec![0xfffffffc, 3, u32::MAX]);
+}
+
+#[test]
+fn test_bigint_long_division() {
+  let mut rng = XorShift::new(0x5eed);
+
+  for (left_magnitude, right_magnitude) in [
+    (2, 2),
+    (3, 2),
+    (8, 3),
+    (20, 19),
+    (64, 7),
+    (150, 80),
+    (300, 1),
+  ] {
+    for _ in 0..20 {
+      let dividend = rng.bigint(left_magnitude);
+      let divisor = rng.bigint(right_magnitude);
+
+      let quotient = &dividend / &divisor;
+
+      assert_eq!(to_num(&quotient), to_num(&dividend) / to_num(&divisor));
+    }
+  }
+}
+
+#[test]
+fn test_bigint_division_signs() {
+  let seven = BigInt::from(7i64);
+  let two = BigInt::from(2i64);
+  let negative_seven = BigInt::from(-7i64);
+  let negative_two = BigInt::from(-2i64);
+
+  assert_eq!(&seven / &two, BigInt::from(3i64));
+  assert_eq!(&negative_seven / &two, BigInt::from(-3i64));
+  assert_eq!(&seven / &negative_two, BigInt::from(-3i64));
+  assert_eq!(&negative_seven / &negative_two, BigInt::from(3i64));
+  assert_eq!((&two / &seven).sign, Sign::Zero);
+
+  let mut big = BigInt::from(u64::MAX);
+  big /= &BigInt::from(u64::MAX);
+  assert_eq!(big, BigInt::one());
+
+  // A word-sized divisor follows the same truncated rule as `i64`.
+  for dividend in [-7i64, -6, -1, 0, 1, 7, i64::MIN + 1] {
+    assert_eq!(&BigInt::from(dividend) % 3u32, dividend % 3, "{dividend}");
+  }
+  // `2^96 = 1 (mod 3)`, so a multi-limb negative dividend leaves `-1`.
+  assert_eq!(&(&BigInt::from(-7i64) << 96) % 3u32, -1);
+}
+
+#[test]
+fn test_bigint_rounding_modes_match_i64() {
+  let operands = [-23i64, -7, -6, -1, 0, 1, 6, 7, 23, i64::MAX, i64::MIN + 1];
+  let divisors = [-7i64, -6, -2, -1, 1, 2, 6, 7, i64::MAX];
+
+  for left in operands {
+    for right in divisors {
+      let dividend = BigInt::from(left);
+      let divisor = BigInt::from(right);
+
+      let (quotient, remainder) = dividend.div_rem(&divisor);
+      assert_eq!(quotient, BigInt::from(left / right));
+      assert_eq!(remainder, BigInt::from(left % right));
+      assert_eq!(&dividend % &divisor, BigInt::from(left % right));
+
+      assert_eq!(dividend.div_euclid(&divisor), left.div_euclid(right).into());
+      assert_eq!(dividend.rem_euclid(&divisor), left.rem_euclid(right).into());
+
+      assert_rounding_matches_num(&dividend, &divisor);
+    }
+  }
+}
+
+#[test]
+fn test_bigint_div_rem_identity() {
+  let mut rng = XorShift::new(0xd1);
+
+  for _ in 0..50 {
+    let dividend = rng.bigint(12);
+    let divisor = rng.bigint(5);
+
+    for (quotient, remainder) in [
+      dividend.div_rem(&divisor),
+      dividend.div_rem_floor(&divisor),
+      dividend.div_rem_ceil(&divisor),
+      dividend.div_rem_euclid(&divisor),
+    ] {
+      assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+    }
+
+    let mut remainder = dividend.clone();
+    remainder %= &divisor;
+    assert_eq!(to_num(&remainder), to_num(&dividend) % to_num(&divisor));
+    assert!(!dividend.rem_euclid(&divisor).sign.is_negative());
+
+    assert_rounding_matches_num(&dividend, &divisor);
+  }
+}
+
+#[test]
+fn test_recursive_division() {
+  let mut rng = XorShift::new(0xb2);
+
+  for (left_magnitude, right_magnitude) in [
+    (9, 4),
+    (16, 8),
+    (33, 9),
+    (64, 31),
+    (100, 50),
+    (257, 64),
+    (400, 17),
+  ] {
+    for crossover in [2, 4, 5] {
+      let dividend = rng.digits(left_magnitude);
+      let divisor = rng.digits(right_magnitude);
+
+      let (quotient, remainder) =
+        BigInt::recursive_divide_digits(&dividend, &divisor, crossover);
+
+      assert_eq!(
+        (quotient, remainder),
+        digital_divide_with_rem_u32(&dividend, &divisor)
+      );
+    }
+  }
+
+  let dividend = rng.bigint(3000);
+  let divisor = rng.bigint(1100);
+  let (quotient, remainder) = dividend.div_rem(&divisor);
+  assert_eq!(to_num(&quotient), to_num(&dividend) / to_num(&divisor));
+  assert_eq!(to_num(&remainder), to_num(&dividend) % to_num(&divisor));
+}
+
+#[test]
+fn test_reciprocal() {
+  let mut rng = XorShift::new(0x1e);
+
+  for (magnitude, precision) in [(1, 3), (5, 900), (40, 2000), (300, 2100)] {
+    let value = rng.bigint(magnitude);
+    let reciprocal = value.reciprocal(precision);
+
+    let expected = (num::BigUint::from(1u32) << (32 * precision))
+      / to_num(&value).magnitude();
+    assert_eq!(to_num(&reciprocal).magnitude(), &expected);
+    assert_eq!(reciprocal.sign, value.sign);
+  }
+}
+
+#[test]
+fn test_newton_division() {
+  let mut rng = XorShift::new(0x2e);
+
+  let dividend = rng.digits(3000);
+  let divisor = rng.digits(1200);
+  assert_eq!(
+    BigInt::newton_divide_digits(&dividend, &divisor),
+    digital_divide_with_rem_u32(&dividend, &divisor)
+  );
+
+  let modulus = rng.bigint(40);
+  let precision = 1000;
+  let reciprocal = Reciprocal::new(&modulus, precision);
+  for magnitude in [1, 39, 40, 41, 500, 1000, 1001] {
+    let value = rng.bigint(magnitude);
+    assert_eq!(reciprocal.div_rem(&value), value.div_rem(&modulus));
+  }
+}
+
+#[test]
+fn test_bigint_shifts() {
+  for value in [-1000i64, -1024, -1, 0, 1, 1023, i64::MAX, i64::MIN + 1] {
+    for bits in [0, 1, 5, 31, 32, 33, 62] {
+      let shifted = &BigInt::from(value) >> bits;
+      assert_eq!(shifted, BigInt::from(value >> bits));
+    }
+    let big = &BigInt::from(value) << 100;
+    assert_eq!(to_num(&big), num::BigInt::from(value) << 100);
+    assert_eq!(&big >> 100, BigInt::from(value));
+  }
+
+  assert_eq!(BigInt::from(1u64 << 40).bit_length(), 41);
+  assert_eq!(BigInt::from(96u32).trailing_zeros(), 5);
+}
@@ -0,0 +1,145 @@
+use crate::{
+  bigint::BigInt,
+  factor::{ecm, factorize, factorize_with, EcmParams},
+  utils::Sign,
+};
+
+#[test]
+fn test_factorize() {
+  let value = BigInt::from(2u32 * 2 * 2 * 5 * 7919);
+  let factorization = factorize(&value);
+  assert_eq!(factorization.to_string(), "2^3 * 5 * 7919");
+  assert_eq!(factorization.product(), value);
+
+  for (value, expected) in [
+    (1i64, "1"),
+    (0, "0"),
+    (-1, "-1"),
+    (-360, "-1 * 2^3 * 3^2 * 5"),
+    (65537 * 65537, "65537^2"),
+    (4294967291 * 3, "3 * 4294967291"),
+  ] {
+    let factorization = factorize(&BigInt::from(value));
+    assert_eq!(factorization.to_string(), expected);
+    assert_eq!(BigInt::from(&factorization), BigInt::from(value));
+  }
+  assert_eq!(factorize(&BigInt::zero()).sign(), Sign::Zero);
+
+  // The Fermat number F6 and Cole's factorization of 2^67 - 1.
+  let f6 = &(&BigInt::one() << 64) + 1;
+  assert_eq!(factorize(&f6).to_string(), "274177 * 67280421310721");
+
+  let mersenne = &(&BigInt::one() << 67) - 1;
+  assert_eq!(
+    factorize(&mersenne).factors(),
+    &[
+      (BigInt::from(193707721u32), 1),
+      (BigInt::from(761838257287u64), 1),
+    ]
+  );
+
+  // A cube of a semiprime exercises perfect-power detection and rho.
+  let semiprime = &BigInt::from(1000000007u32) * &BigInt::from(998244353u32);
+  let cube = semiprime.pow(3);
+  let factorization = factorize(&cube);
+  assert_eq!(factorization.to_string(), "998244353^3 * 1000000007^3");
+  assert_eq!(factorization.product(), cube);
+
+  let mixed = &(&cube * &f6) * &BigInt::from(-48i64);
+  let factorization = factorize(&mixed);
+  assert_eq!(
+    factorization.to_string(),
+    "-1 * 2^4 * 3 * 274177 * 998244353^3 * 1000000007^3 * 67280421310721"
+  );
+  assert_eq!(factorization.product(), mixed);
+}
+
+#[test]
+fn test_ecm() {
+  let small = BigInt::from(10u32).pow(14).next_prime();
+  let large = BigInt::from(10u32).pow(22).next_prime();
+  let composite = &small * &large;
+  let params = EcmParams {
+    b1: 2000,
+    b2: 200000,
+    curves: 100,
+    seed: 7,
+  };
+
+  let divisor = ecm(&composite, &params).unwrap();
+  assert!(divisor == small || divisor == large);
+  assert_eq!(ecm(&composite, &params), Some(divisor));
+
+  assert_eq!(ecm(&BigInt::from(1000u32), &params), None);
+
+  // Rho gives up on the 15-digit factor and ECM takes over.
+  let value = &(&composite * &composite) * &BigInt::from(-6i64);
+  let factorization = factorize_with(&value, &params);
+  assert_eq!(
+    factorization.factors(),
+    &[
+      (BigInt::from(2u32), 1),
+      (BigInt::from(3u32), 1),
+      (small, 2),
+      (large, 2)
+    ]
+  );
+  assert_eq!(factorization.product(), value);
+}
+
+#[cfg(feature = "siqs")]
+#[test]
+fn test_siqs() {
+  use crate::factor::siqs;
+
+  for (lhs_digits, rhs_digits) in [(11, 12), (17, 18), (24, 25)] {
+    let lhs = BigInt::from(10u32).pow(lhs_digits).next_prime();
+    let rhs = (&BigInt::from(10u32).pow(rhs_digits) * 3).next_prime();
+    let composite = &lhs * &rhs;
+
+    let divisor = siqs(&composite).unwrap();
+    assert!(divisor == lhs || divisor == rhs, "{composite}");
+  }
+
+  assert_eq!(siqs(&BigInt::from(1000003u32 * 1009)), None);
+}
+
+#[cfg(feature = "siqs")]
+#[test]
+fn test_siqs_dependencies() {
+  use super::helpers::XorShift;
+  use crate::factor::siqs::matrix::dependencies;
+
+  // Shaped like the matrix for the largest factor base: the `i`th prime
+  // turns up in a relation with odd exponent roughly in proportion to
+  // `1 / p_i`, so columns are drawn log-uniformly.
+  let columns = 12001;
+  let mut rng = XorShift::new(20);
+  let rows: Vec<Vec<usize>> = (0..columns + 24)
+    .map(|_| {
+      let mut row: Vec<usize> = (0..20)
+        .map(|_| {
+          let unit = rng.next_u32() as f64 / (u32::MAX as f64 + 1.0);
+          (columns as f64).powf(unit) as usize - 1
+        })
+        .collect();
+      row.sort_unstable();
+      row.dedup();
+      row
+    })
+    .collect();
+
+  let found = dependencies(&rows, columns);
+  assert!(found.len() >= 24, "{}", found.len());
+  for dependency in found {
+    assert!(!dependency.is_empty());
+
+    let mut parity = vec![false; columns];
+    for row in dependency {
+      for column in &rows[row] {
+        parity[*column] ^= true;
+      }
+    }
+    assert!(parity.iter().all(|odd| !odd));
+  }
+}
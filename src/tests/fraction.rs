@@ -0,0 +1,117 @@
+use num::{BigInt as NumBigInt, BigRational, One, Zero};
+
+use super::helpers::to_num;
+use crate::{bigint::BigInt, fraction::Fraction};
+
+fn to_rational(value: &Fraction) -> BigRational {
+  BigRational::new(to_num(value.numerator()), to_num(value.denominator()))
+}
+
+fn fraction(numerator: i64, denominator: i64) -> Fraction {
+  Fraction::new(BigInt::from(numerator), BigInt::from(denominator))
+}
+
+#[test]
+fn test_fraction_arithmetic() {
+  let (half, third) = (fraction(1, 2), fraction(-2, -6));
+
+  assert_eq!(&half + &third, fraction(5, 6));
+  assert_eq!(&third - &half, fraction(-1, 6));
+  assert_eq!(&half * &third, fraction(1, 6));
+  assert_eq!(&half / &third, fraction(3, 2));
+  assert_eq!(&half - &half, Fraction::from(BigInt::zero()));
+  assert_eq!(fraction(-4, 6).to_string(), "-2/3");
+  assert_eq!(fraction(12, -4).to_string(), "-3");
+}
+
+#[test]
+fn test_bernoulli() {
+  let expected = [
+    (1, 1),
+    (-1, 2),
+    (1, 6),
+    (0, 1),
+    (-1, 30),
+    (0, 1),
+    (1, 42),
+    (0, 1),
+    (-1, 30),
+    (0, 1),
+    (5, 66),
+    (0, 1),
+    (-691, 2730),
+    (0, 1),
+    (7, 6),
+  ];
+  let numbers = Fraction::bernoulli_numbers(expected.len() as u64 - 1);
+  for (n, (numerator, denominator)) in expected.iter().enumerate() {
+    assert_eq!(numbers[n], fraction(*numerator, *denominator), "B_{n}");
+    assert_eq!(Fraction::bernoulli(n as u64), numbers[n], "B_{n}");
+  }
+
+  // The Akiyama-Tanigawa algorithm, which yields `B_1 = +1/2`.
+  let count = 120;
+  let mut row: Vec<BigRational> = (0..=count)
+    .map(|m| BigRational::new(1.into(), (m + 1).into()))
+    .collect();
+  let numbers = Fraction::bernoulli_numbers(count as u64);
+  for (n, number) in numbers.iter().enumerate() {
+    let expected = match n {
+      1 => -row[0].clone(),
+      _ => row[0].clone(),
+    };
+    assert_eq!(to_rational(number), expected, "B_{n}");
+
+    for m in 0..count - n {
+      row[m] =
+        (&row[m] - &row[m + 1]) * BigRational::from_integer((m + 1).into());
+    }
+  }
+}
+
+#[test]
+fn test_bernoulli_by_zeta() {
+  let numbers = Fraction::bernoulli_numbers(400);
+  for n in (4..=400).step_by(2) {
+    assert_eq!(Fraction::bernoulli_by_zeta(n), numbers[n as usize], "B_{n}");
+    assert_eq!(Fraction::bernoulli(n), numbers[n as usize], "B_{n}");
+  }
+}
+
+#[test]
+fn test_harmonic() {
+  assert_eq!(Fraction::harmonic(0), Fraction::from(BigInt::zero()));
+  assert_eq!(Fraction::harmonic(1), Fraction::from(BigInt::one()));
+  assert_eq!(Fraction::harmonic(4), fraction(25, 12));
+
+  let mut expected = BigRational::zero();
+  for n in 1..=1500u32 {
+    expected += BigRational::new(1.into(), n.into());
+    if n % 100 == 0 || n < 20 {
+      assert_eq!(to_rational(&Fraction::harmonic(n as u64)), expected, "{n}");
+    }
+  }
+
+  // Summed over the common denominator `lcm(1, ..., n)` and reduced by num.
+  let n = 100000u64;
+  let mut composite = vec![false; n as usize + 1];
+  let mut lcm = NumBigInt::one();
+  for prime in 2..=n {
+    if composite[prime as usize] {
+      continue;
+    }
+    for multiple in (prime * prime..=n).step_by(prime as usize) {
+      composite[multiple as usize] = true;
+    }
+
+    let mut power = prime;
+    while power * prime <= n {
+      power *= prime;
+    }
+    lcm *= power;
+  }
+
+  let numerator: NumBigInt = (1..=n).map(|k| &lcm / k).sum();
+  let expected = BigRational::new(numerator, lcm);
+  assert_eq!(to_rational(&Fraction::harmonic(n)), expected);
+}
@@ -0,0 +1,119 @@
+use num::Integer;
+
+use super::helpers::{to_num, XorShift};
+use crate::{
+  bigint::{impls::gcd::GcdCrossovers, BigInt, BigIntError},
+  fraction::Fraction,
+};
+
+#[test]
+fn test_gcd() {
+  let mut rng = XorShift::new(0x6c);
+
+  for (lhs_magnitude, rhs_magnitude) in
+    [(1, 1), (2, 1), (3, 3), (20, 18), (64, 64), (150, 7)]
+  {
+    let common = rng.bigint(2);
+    let lhs = &rng.bigint(lhs_magnitude) * &common;
+    let rhs = &rng.bigint(rhs_magnitude) * &common;
+
+    assert_eq!(to_num(&lhs.gcd(&rhs)), to_num(&lhs).gcd(&to_num(&rhs)));
+    assert_eq!(to_num(&lhs.lcm(&rhs)), to_num(&lhs).lcm(&to_num(&rhs)));
+
+    let (gcd, x, y) = lhs.extended_gcd(&rhs);
+    assert_eq!(to_num(&gcd), to_num(&lhs).gcd(&to_num(&rhs)));
+    assert_eq!(&(&lhs * &x) + &(&rhs * &y), gcd);
+  }
+
+  let value = BigInt::from(-12i64);
+  assert_eq!(value.gcd(&BigInt::zero()), BigInt::from(12u32));
+  assert_eq!(BigInt::zero().gcd(&BigInt::zero()), BigInt::zero());
+  assert!(value.lcm(&BigInt::zero()).is_zero());
+
+  let (gcd, x, y) = value.extended_gcd(&BigInt::zero());
+  assert_eq!(
+    (gcd, x, y),
+    (BigInt::from(12u32), BigInt::negative_one(), BigInt::zero())
+  );
+
+  let (gcd, x, y) = BigInt::from(-240i64).extended_gcd(&BigInt::from(-46i64));
+  assert_eq!(gcd, BigInt::from(2u32));
+  assert_eq!(
+    &(&BigInt::from(-240i64) * &x) + &(&BigInt::from(-46i64) * &y),
+    gcd
+  );
+}
+
+#[test]
+fn test_mod_inverse() {
+  let mut rng = XorShift::new(0x1d);
+
+  for magnitude in [1, 2, 9, 40] {
+    let mut modulus = rng.bigint(magnitude).abs();
+    modulus.digits[0] |= 1;
+    let value = &(&rng.bigint(magnitude + 1) << 1) + &BigInt::one();
+
+    if value.gcd(&modulus) != BigInt::one() {
+      continue;
+    }
+
+    let inverse = value.mod_inverse(&modulus).unwrap();
+    assert!(inverse < modulus && !inverse.sign.is_negative());
+    assert_eq!((&value * &inverse).rem_euclid(&modulus), BigInt::one());
+  }
+
+  let modulus = BigInt::from(12u32);
+  assert_eq!(
+    BigInt::from(-5i64).mod_inverse(&modulus),
+    Ok(BigInt::from(7u32))
+  );
+  assert_eq!(
+    BigInt::from(4u32).mod_inverse(&modulus),
+    Err(BigIntError::NotInvertible)
+  );
+  assert_eq!(
+    BigInt::from(4u32).mod_inverse(&BigInt::zero()),
+    Err(BigIntError::ZeroModulus)
+  );
+}
+
+#[test]
+fn test_fraction_lowest_terms() {
+  let fraction = Fraction::new(BigInt::from(18u32), BigInt::from(-12i64));
+
+  assert_eq!(fraction.numerator(), &BigInt::from(-3i64));
+  assert_eq!(fraction.denominator(), &BigInt::from(2u32));
+}
+
+#[test]
+fn test_half_gcd() {
+  let mut rng = XorShift::new(0x4a);
+  // Low enough that the recursion runs several levels deep at every size.
+  let small_crossovers = GcdCrossovers {
+    half_gcd: 40,
+    base: 8,
+  };
+
+  for (lhs_magnitude, rhs_magnitude, common_magnitude) in [
+    (600, 600, 1),
+    (1500, 1400, 300),
+    (2500, 900, 40),
+    (10000, 10000, 1),
+    (12000, 11000, 700),
+  ] {
+    let common = rng.bigint(common_magnitude);
+    let lhs = &rng.bigint(lhs_magnitude) * &common;
+    let rhs = &rng.bigint(rhs_magnitude) * &common;
+    let expected = to_num(&lhs).gcd(&to_num(&rhs));
+
+    assert_eq!(to_num(&lhs.gcd(&rhs)), expected);
+    assert_eq!(
+      to_num(&lhs.gcd_with_crossovers(&rhs, small_crossovers)),
+      expected
+    );
+
+    let (gcd, x, y) = lhs.extended_gcd(&rhs);
+    assert_eq!(to_num(&gcd), expected);
+    assert_eq!(&(&lhs * &x) + &(&rhs * &y), gcd);
+  }
+}
@@ -0,0 +1,45 @@
+use num::bigint::Sign as NumSign;
+
+use crate::{bigint::BigInt, utils::Sign};
+
+pub struct XorShift(u64);
+
+impl XorShift {
+  pub fn new(seed: u64) -> Self {
+    Self(seed | 1)
+  }
+
+  pub fn next_u32(&mut self) -> u32 {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    (self.0 >> 32) as u32
+  }
+
+  pub fn digits(&mut self, magnitude: usize) -> Vec<u32> {
+    let mut digits: Vec<u32> =
+      (0..magnitude).map(|_| self.next_u32()).collect();
+    if let Some(top) = digits.last_mut() {
+      *top |= 1;
+    }
+    digits
+  }
+
+  pub fn bigint(&mut self, magnitude: usize) -> BigInt {
+    let sign = if self.next_u32() & 1 == 0 {
+      Sign::Positive
+    } else {
+      Sign::Negative
+    };
+    BigInt::from_parts(sign, self.digits(magnitude))
+  }
+}
+
+pub fn to_num(value: &BigInt) -> num::BigInt {
+  let sign = match value.sign {
+    Sign::Positive => NumSign::Plus,
+    Sign::Negative => NumSign::Minus,
+    Sign::Zero => NumSign::NoSign,
+  };
+  num::BigInt::from_slice(sign, &value.digits)
+}
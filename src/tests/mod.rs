@@ -1,7 +1,22 @@
+mod combinatorics;
+mod crossovers;
+mod divide;
+mod factor;
+mod fraction;
+mod gcd;
+mod helpers;
+mod modular;
+mod multiply;
+mod prime;
+mod root;
+mod sequence;
+mod sieve;
+mod symbol;
+
 use std::time::Instant;
 
 use crate::{
-  bigint::BigInt,
+  bigint::{BigInt, BigIntError},
   utils::{
     digital_add, digital_add_in_place, digital_multiply_u32,
     digital_scalar_divide_in_place_u32, digital_subtract, karatsuba_mul,
@@ -16,7 +31,7 @@ fn test_display() {
   let hundo = BigInt::from(target_fact);
 
   let timer = Instant::now();
-  let hundo_facto = hundo.fact();
+  let hundo_facto = hundo.fact().unwrap();
   let finished = timer.elapsed();
 
   println!("Computed {target_fact}! in {:?}", finished);
@@ -191,8 +206,22 @@ fn test_bigint_bigint_multiply() {
 #[test]
 fn test_factorial() {
   let hundo: BigInt = 100u64.into();
-  let hundo_fact = hundo.fact();
+  let hundo_fact = hundo.fact().unwrap();
   println!("{:?}", hundo_fact.digits);
+
+  let mut expected = num::BigUint::from(1u32);
+  for n in 0..=3000u32 {
+    if n > 0 {
+      expected *= n;
+    }
+    let fact = BigInt::from(n).fact().unwrap();
+    assert_eq!(num::BigUint::from_slice(&fact.digits), expected, "{n}!");
+  }
+
+  assert_eq!(
+    BigInt::from(-1i32).fact().unwrap_err(),
+    BigIntError::NegativeFactorial
+  );
 }
 
 #[test]
@@ -216,19 +245,97 @@ fn test_karatsuba_multiplication() {
   let some_digits = test_vec.clone();
   let some_other_digits = test_vec.clone();
 
-  let mut timer = Instant::now();
   let result = karatsuba_mul(&some_digits, &some_other_digits, crossover);
-  let k_time = timer.elapsed();
-
-  // timer = Instant::now();
-  // let digital_result = digital_multiply_u32(&some_digits, &some_other_digits);
-  // let reg_time = timer.elapsed();
-
-  // assert_eq!(result, digital_result);
-  // println!(
-  //   "Karatsuba took {:?}\n\
-  //   Tranditional mult took {:?}\n\
-  //   with a crossover of {crossover} multiplying {digits} digits of {digit}",
-  //   k_time, reg_time
-  // );
+
+  let expected = num::BigUint::from_slice(&some_digits)
+    * num::BigUint::from_slice(&some_other_digits);
+  assert_eq!(num::BigUint::from_slice(&result), expected);
+}
+
+#[test]
+fn test_digital_wrap_display() {
+  assert_eq!(DigitalWrap::Max.to_string(), "Maximum Value");
+  assert_eq!(DigitalWrap::Ten.to_string(), "10");
+  assert_eq!(DigitalWrap::U32(7).to_string(), "7u32");
+  assert_eq!(format!("{}", DigitalWrap::U8(16)), "16u8");
+}
+
+#[test]
+fn test_ordering() {
+  assert!(Sign::Negative < Sign::Zero);
+  assert!(Sign::Zero < Sign::Positive);
+  assert!(Sign::Negative < Sign::Positive);
+  assert!(Sign::Positive > Sign::Negative);
+
+  let mut values: Vec<BigInt> = [7i64, -3, 0, -300, 2, -4]
+    .into_iter()
+    .map(BigInt::from)
+    .collect();
+  values.sort();
+  let expected: Vec<BigInt> = [-300i64, -4, -3, 0, 2, 7]
+    .into_iter()
+    .map(BigInt::from)
+    .collect();
+  assert_eq!(values, expected);
+
+  assert!(BigInt::from(-5i64) < BigInt::from(-2i64));
+  assert!(BigInt::from(5i64) > BigInt::from(2i64));
+}
+
+#[test]
+fn test_signed_add_subtract() {
+  let (five, three) = (BigInt::from(5i64), BigInt::from(3i64));
+  let (negative_five, negative_three) =
+    (BigInt::from(-5i64), BigInt::from(-3i64));
+
+  assert_eq!(&negative_five + &three, BigInt::from(-2i64));
+  assert_eq!(&five + &negative_three, BigInt::from(2i64));
+  assert_eq!(&negative_five + &negative_three, BigInt::from(-8i64));
+  assert_eq!(&three - &five, BigInt::from(-2i64));
+  assert_eq!(&negative_three - &negative_five, BigInt::from(2i64));
+  assert_eq!(&negative_three - &five, BigInt::from(-8i64));
+  assert_eq!(&BigInt::zero() - &five, negative_five);
+  assert_eq!(&five - &five, BigInt::zero());
+
+  assert_eq!(&negative_five + 3, BigInt::from(-2i64));
+  assert_eq!(&negative_five - 3, BigInt::from(-8i64));
+  assert_eq!(&three - 5, BigInt::from(-2i64));
+
+  let (difference, sign) =
+    digital_subtract(&[0, 0, 1u32], &[1], DigitalWrap::Max);
+  assert_eq!(difference, vec![u32::MAX, u32::MAX]);
+  assert_eq!(sign, Sign::Positive);
+
+  let (difference, sign) = digital_subtract(&[0u32], &[4], DigitalWrap::Max);
+  assert_eq!(difference, vec![4]);
+  assert_eq!(sign, Sign::Negative);
+}
+
+#[test]
+fn test_from_ints() {
+  assert_eq!(BigInt::from(0x1_0000_0002u64).digits, vec![2, 1]);
+  assert_eq!(BigInt::from(u64::MAX).digits, vec![u32::MAX, u32::MAX]);
+  assert_eq!(BigInt::from(7u64).digits, vec![7]);
+  assert_eq!(BigInt::from(0u64).sign, Sign::Zero);
+
+  let negative = BigInt::from(-0x3_0000_0004i64);
+  assert_eq!(negative.digits, vec![4, 3]);
+  assert_eq!(negative.sign, Sign::Negative);
+  assert_eq!(BigInt::from(i64::MIN).digits, vec![0, 0x80000000]);
+}
+
+#[test]
+fn test_multiply_signs_and_zero() {
+  let (three, negative_four) = (BigInt::from(3i64), BigInt::from(-4i64));
+
+  assert_eq!(&three * &negative_four, BigInt::from(-12i64));
+  assert_eq!(&negative_four * &three, BigInt::from(-12i64));
+  assert_eq!(&negative_four * &negative_four, BigInt::from(16i64));
+
+  let zero = BigInt::zero();
+  assert_eq!((&zero * &negative_four).sign, Sign::Zero);
+  assert_eq!((&negative_four * &zero).sign, Sign::Zero);
+
+  assert_eq!(digital_multiply_u32(&[], &[5]), vec![0]);
+  assert_eq!(digital_multiply_u32(&[5], &[]), vec![0]);
 }
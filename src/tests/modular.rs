@@ -0,0 +1,152 @@
+use num::Signed;
+
+use super::helpers::{to_num, XorShift};
+use crate::bigint::{BarrettReducer, BigInt, BigIntError, MontgomeryContext};
+
+#[test]
+fn test_modpow() {
+  let mut rng = XorShift::new(0x3c);
+
+  for (base_magnitude, exponent_magnitude, modulus_magnitude) in
+    [(1, 1, 1), (3, 1, 2), (8, 4, 8), (20, 9, 16), (2, 40, 33)]
+  {
+    let base = rng.bigint(base_magnitude);
+    let exponent = rng.bigint(exponent_magnitude).abs();
+    let modulus = rng.bigint(modulus_magnitude);
+
+    let expected = {
+      let modulus = to_num(&modulus).abs();
+      to_num(&base).modpow(&to_num(&exponent), &modulus)
+    };
+
+    assert_eq!(to_num(&base.modpow(&exponent, &modulus).unwrap()), expected);
+  }
+
+  let modulus = BigInt::from(1000u32);
+  assert_eq!(
+    BigInt::from(-7i64).modpow(&BigInt::from(3u32), &modulus),
+    Ok(BigInt::from(657u32))
+  );
+  assert_eq!(
+    BigInt::from(5u32).modpow(&BigInt::zero(), &modulus),
+    Ok(BigInt::one())
+  );
+  assert_eq!(
+    BigInt::from(5u32).modpow(&BigInt::zero(), &BigInt::one()),
+    Ok(BigInt::zero())
+  );
+  assert_eq!(
+    BigInt::from(5u32).modpow(&BigInt::one(), &BigInt::zero()),
+    Err(BigIntError::ZeroModulus)
+  );
+  assert_eq!(
+    BigInt::from(5u32).modpow(&BigInt::negative_one(), &modulus),
+    Err(BigIntError::NegativeExponent)
+  );
+}
+
+#[test]
+fn test_montgomery_context() {
+  let mut rng = XorShift::new(0x4d);
+
+  for magnitude in [1, 2, 5, 40, 130] {
+    let mut modulus = rng.bigint(magnitude).abs();
+    modulus.digits[0] |= 1;
+
+    let context = MontgomeryContext::new(&modulus).unwrap();
+    let lhs = rng.bigint(magnitude + 1);
+    let rhs = rng.bigint(magnitude);
+    let exponent = rng.bigint(3).abs();
+
+    let lhs_form = context.to_montgomery(&lhs);
+    let rhs_form = context.to_montgomery(&rhs);
+    assert_eq!(context.from_montgomery(&lhs_form), lhs.rem_euclid(&modulus));
+
+    assert_eq!(
+      context.from_montgomery(&context.mul(&lhs_form, &rhs_form)),
+      (&lhs * &rhs).rem_euclid(&modulus)
+    );
+    assert_eq!(
+      context.from_montgomery(&context.square(&lhs_form)),
+      lhs.square().rem_euclid(&modulus)
+    );
+
+    let power = context.pow(&lhs_form, &exponent);
+    assert_eq!(
+      to_num(&context.from_montgomery(&power)),
+      to_num(&lhs).modpow(&to_num(&exponent), &to_num(&modulus))
+    );
+
+    // Negative and unreduced operands are taken modulo `m`, not truncated.
+    let wide = rng.bigint(3 * magnitude).abs();
+    let mut negative = lhs_form.clone();
+    negative.negate();
+    let r_inverse = context.one().mod_inverse(&modulus).unwrap();
+    assert_eq!(
+      context.mul(&wide, &negative),
+      (&(&wide * &negative) * &r_inverse).rem_euclid(&modulus)
+    );
+    assert_eq!(
+      context.square(&wide),
+      (&wide.square() * &r_inverse).rem_euclid(&modulus)
+    );
+    assert_eq!(
+      context.from_montgomery(&wide),
+      (&wide * &r_inverse).rem_euclid(&modulus)
+    );
+  }
+
+  assert_eq!(
+    MontgomeryContext::new(&BigInt::from(10u32)).unwrap_err(),
+    BigIntError::EvenModulus
+  );
+  assert_eq!(
+    MontgomeryContext::new(&BigInt::zero()).unwrap_err(),
+    BigIntError::ZeroModulus
+  );
+}
+
+#[test]
+fn test_barrett_reducer() {
+  let mut rng = XorShift::new(0xb4);
+
+  for magnitude in [1, 2, 7, 64] {
+    let modulus = rng.bigint(magnitude).abs();
+    let reducer = BarrettReducer::new(&modulus).unwrap();
+
+    for value_magnitude in [1, magnitude, 2 * magnitude, 5 * magnitude + 3] {
+      let value = rng.bigint(value_magnitude);
+      assert_eq!(reducer.reduce(&value), value.rem_euclid(&modulus));
+    }
+
+    let lhs = rng.bigint(magnitude);
+    let rhs = rng.bigint(magnitude + 2);
+    assert_eq!(
+      reducer.mul_mod(&lhs, &rhs),
+      (&lhs * &rhs).rem_euclid(&modulus)
+    );
+
+    let exponent = rng.bigint(4).abs();
+    assert_eq!(
+      to_num(&reducer.pow_mod(&lhs, &exponent)),
+      to_num(&lhs).modpow(&to_num(&exponent), &to_num(&modulus))
+    );
+  }
+
+  let power_of_ten = BigInt::from(10_000_000_000u64);
+  let reducer = BarrettReducer::new(&power_of_ten).unwrap();
+  assert_eq!(
+    reducer.pow_mod(&BigInt::from(7u32), &BigInt::from(1000u32)),
+    BigInt::from(7u32)
+      .modpow(&BigInt::from(1000u32), &power_of_ten)
+      .unwrap()
+  );
+  assert_eq!(
+    reducer.reduce(&BigInt::from(-1i64)),
+    BigInt::from(9_999_999_999u64)
+  );
+  assert_eq!(
+    BarrettReducer::new(&BigInt::zero()).unwrap_err(),
+    BigIntError::ZeroModulus
+  );
+}
@@ -0,0 +1,197 @@
+use super::helpers::{to_num, XorShift};
+use crate::{
+  bigint::BigInt,
+  utils::{
+    digital_multiply_dispatch_u32, digital_square_dispatch_u32,
+    digital_square_u32, karatsuba_square, ntt_mul, toom_3_mul, toom_4_mul,
+    MultiplyCrossovers,
+  },
+};
+
+const SMALL_CROSSOVERS: MultiplyCrossovers = MultiplyCrossovers {
+  karatsuba: 4,
+  toom_3: 9,
+  toom_4: 16,
+  ntt: 40,
+};
+
+fn product(lhs: &[u32], rhs: &[u32]) -> num::BigUint {
+  num::BigUint::from_slice(lhs) * num::BigUint::from_slice(rhs)
+}
+
+#[test]
+fn test_toom_cook_multiplication() {
+  let mut rng = XorShift::new(0x7003);
+
+  for (left_magnitude, right_magnitude) in [
+    (3, 3),
+    (7, 5),
+    (12, 12),
+    (31, 17),
+    (64, 64),
+    (100, 51),
+    (250, 249),
+  ] {
+    let lhs = rng.digits(left_magnitude);
+    let rhs = rng.digits(right_magnitude);
+
+    let expected = product(&lhs, &rhs);
+    assert_eq!(
+      num::BigUint::from_slice(&toom_3_mul(&lhs, &rhs, SMALL_CROSSOVERS)),
+      expected
+    );
+    assert_eq!(
+      num::BigUint::from_slice(&toom_4_mul(&lhs, &rhs, SMALL_CROSSOVERS)),
+      expected
+    );
+  }
+
+  let saturated = vec![u32::MAX; 97];
+  let sparse = [vec![0; 60], vec![1], vec![0; 20], vec![u32::MAX]].concat();
+  for (lhs, rhs) in [(&saturated, &saturated), (&saturated, &sparse)] {
+    let expected = product(lhs, rhs);
+    assert_eq!(
+      num::BigUint::from_slice(&toom_3_mul(lhs, rhs, SMALL_CROSSOVERS)),
+      expected
+    );
+    assert_eq!(
+      num::BigUint::from_slice(&toom_4_mul(lhs, rhs, SMALL_CROSSOVERS)),
+      expected
+    );
+  }
+}
+
+#[test]
+fn test_multiply_dispatch() {
+  let mut rng = XorShift::new(0xd15);
+
+  for (left_magnitude, right_magnitude) in
+    [(1, 1), (2, 300), (5, 5), (40, 300), (301, 77), (500, 499)]
+  {
+    let lhs = rng.digits(left_magnitude);
+    let rhs = rng.digits(right_magnitude);
+
+    let result = digital_multiply_dispatch_u32(&lhs, &rhs, SMALL_CROSSOVERS);
+    assert_eq!(num::BigUint::from_slice(&result), product(&lhs, &rhs));
+    assert_ne!(result.last(), Some(&0));
+  }
+
+  for magnitude in [100, 1000, 3000] {
+    let lhs = rng.bigint(magnitude);
+    let rhs = rng.bigint(magnitude + 7);
+    assert_eq!(to_num(&(&lhs * &rhs)), to_num(&lhs) * to_num(&rhs));
+  }
+
+  let mut zero = BigInt::zero();
+  zero *= &rng.bigint(200);
+  assert!(zero.is_zero());
+}
+
+#[test]
+fn test_ntt_multiplication() {
+  let mut rng = XorShift::new(0x4e77);
+
+  for (left_magnitude, right_magnitude) in
+    [(1, 1), (2, 1), (17, 5), (64, 64), (1000, 999), (4096, 3)]
+  {
+    let lhs = rng.digits(left_magnitude);
+    let rhs = rng.digits(right_magnitude);
+    assert_eq!(
+      num::BigUint::from_slice(&ntt_mul(&lhs, &rhs)),
+      product(&lhs, &rhs)
+    );
+  }
+
+  // Saturated digits give the largest possible convolution coefficients.
+  let saturated = vec![u32::MAX; 20000];
+  let square = ntt_mul(&saturated, &saturated);
+  assert_eq!(
+    num::BigUint::from_slice(&square),
+    product(&saturated, &saturated)
+  );
+}
+
+#[test]
+fn test_squaring() {
+  let mut rng = XorShift::new(0x5a);
+
+  for magnitude in [1, 2, 3, 8, 33, 64, 129, 500] {
+    let digits = rng.digits(magnitude);
+    let expected = product(&digits, &digits);
+
+    assert_eq!(
+      num::BigUint::from_slice(&digital_square_u32(&digits)),
+      expected
+    );
+    assert_eq!(
+      num::BigUint::from_slice(&karatsuba_square(&digits, 4)),
+      expected
+    );
+    assert_eq!(
+      num::BigUint::from_slice(&digital_square_dispatch_u32(
+        &digits,
+        SMALL_CROSSOVERS
+      )),
+      expected
+    );
+  }
+
+  let saturated = vec![u32::MAX; 77];
+  assert_eq!(
+    num::BigUint::from_slice(&digital_square_u32(&saturated)),
+    product(&saturated, &saturated)
+  );
+
+  let value = rng.bigint(2000);
+  let squared = value.square();
+  assert_eq!(to_num(&squared), to_num(&value) * to_num(&value));
+  assert_eq!(&value * &value, squared);
+
+  // Equal operands held in separate values, of either sign, take the same
+  // squaring path through `MulAssign`.
+  let copy = value.clone();
+  assert_eq!(to_num(&(&value * &copy)), to_num(&value) * to_num(&value));
+  for sign in [1i64, -1] {
+    let mut lhs = &value.abs() * &BigInt::from(sign);
+    let rhs = lhs.clone();
+    lhs *= &rhs;
+    assert_eq!(to_num(&lhs), to_num(&rhs) * to_num(&rhs));
+  }
+
+  let mut negative = BigInt::from(-12345i64);
+  negative.square_assign();
+  assert_eq!(negative, BigInt::from(152399025i64));
+  assert!(BigInt::zero().square().is_zero());
+}
+
+#[test]
+fn test_pow() {
+  let mut rng = XorShift::new(0x9e);
+
+  for exponent in [0u32, 1, 2, 3, 7, 64, 129] {
+    let base = rng.bigint(5);
+    let expected = num::pow::pow(to_num(&base), exponent as usize);
+
+    assert_eq!(to_num(&base.pow(exponent as u64)), expected);
+    assert_eq!(to_num(&base.pow_big(&BigInt::from(exponent))), expected);
+  }
+
+  let mut value = BigInt::from(-3i64);
+  value.pow_assign(5);
+  assert_eq!(value, BigInt::from(-243i64));
+
+  let huge = &BigInt::one() << 100;
+  assert_eq!(BigInt::negative_one().pow_big(&huge), BigInt::one());
+  assert_eq!(
+    BigInt::negative_one().pow_big(&(&huge + &BigInt::one())),
+    BigInt::negative_one()
+  );
+  assert!(BigInt::zero().pow_big(&huge).is_zero());
+  assert_eq!(BigInt::zero().pow(0), BigInt::one());
+
+  let three = BigInt::from(3u32);
+  assert_eq!(three.checked_pow(20, 32), Some(BigInt::from(3486784401u64)));
+  assert_eq!(three.checked_pow(21, 32), None);
+  assert_eq!(three.checked_pow(1_000_000_000_000, 64), None);
+  assert_eq!(BigInt::one().checked_pow(u64::MAX, 1), Some(BigInt::one()));
+}
@@ -0,0 +1,140 @@
+use crate::bigint::BigInt;
+
+fn is_prime_naive(value: u64) -> bool {
+  value >= 2
+    && (2..)
+      .take_while(|p| p * p <= value)
+      .all(|p| !value.is_multiple_of(p))
+}
+
+fn mersenne(exponent: usize) -> BigInt {
+  &(&BigInt::one() << exponent) - 1
+}
+
+#[test]
+fn test_is_probable_prime() {
+  for value in (0..5000).chain(65000..66000) {
+    let expected = is_prime_naive(value);
+    let value = BigInt::from(value);
+
+    assert_eq!(value.is_probable_prime(1), expected, "{value}");
+    assert_eq!(value.is_prime_bpsw(), expected, "{value}");
+  }
+
+  assert!(!BigInt::from(-7i64).is_probable_prime(10));
+  assert!(!BigInt::from(-7i64).is_prime_bpsw());
+
+  // Carmichael numbers and strong pseudoprimes to many small bases.
+  for value in [561u64, 41041, 3215031751, 3825123056546413051] {
+    assert!(!BigInt::from(value).is_probable_prime(0), "{value}");
+    assert!(!BigInt::from(value).is_prime_bpsw(), "{value}");
+  }
+
+  for exponent in [61, 89, 107, 127, 521] {
+    let prime = mersenne(exponent);
+    assert!(prime.is_probable_prime(20), "2^{exponent} - 1");
+    assert!(prime.is_prime_bpsw(), "2^{exponent} - 1");
+  }
+
+  for (lhs, rhs) in [(61, 89), (89, 89), (127, 107)] {
+    let composite = &mersenne(lhs) * &mersenne(rhs);
+    assert!(!composite.is_probable_prime(20));
+    assert!(!composite.is_prime_bpsw());
+  }
+
+  // Above 64 bits even zero rounds must not accept a composite with no
+  // small factor.
+  let semiprime = &mersenne(61) * &BigInt::from(1000000007u32);
+  assert!(!semiprime.is_probable_prime(0));
+
+  // 2^67 - 1 is the composite Mersenne number factored by Cole.
+  assert!(!mersenne(67).is_probable_prime(20));
+  assert!(!mersenne(67).is_prime_bpsw());
+  assert!(!(&mersenne(127) + 2).is_prime_bpsw());
+}
+
+#[test]
+fn test_bpsw_steps() {
+  // Strong pseudoprimes to base 2 above 2^64 with no factor below 256, so
+  // only the strong Lucas step can reject them. `is_probable_prime(0)` runs
+  // nothing but the base-2 test there.
+  let carmichael = &(&BigInt::from(1505341u32) * 3010681) * 4516021;
+  let base_2_pseudoprimes =
+    [71, 73, 79, 101, 109].map(mersenne).into_iter().chain([carmichael]);
+  for value in base_2_pseudoprimes {
+    assert!(value.is_probable_prime(0), "{value}");
+    assert!(!value.is_prime_bpsw(), "{value}");
+  }
+
+  // Strong Lucas pseudoprimes for Selfridge's parameters (D = 5, P = 1,
+  // Q = -1) above 2^64 with no factor below 256: the base-2 step must
+  // reject them.
+  let lucas_pseudoprimes = [
+    &[5813u32, 13337, 796517, 100648549][..],
+    &[21317, 40697, 176357, 593029],
+    &[114113, 1939937, 5819813, 100648549],
+    &[114113, 1939937, 40738697, 100648549],
+  ];
+  for factors in lucas_pseudoprimes {
+    let value = factors
+      .iter()
+      .fold(BigInt::one(), |product, factor| &product * *factor);
+    assert!(!value.is_probable_prime(0), "{value}");
+    assert!(!value.is_prime_bpsw(), "{value}");
+  }
+
+  let primes = [
+    &(&BigInt::one() << 255) - 19,
+    &(&BigInt::one() << 130) - 5,
+    // Ferrier's prime, the largest found without a computer.
+    &(&(&BigInt::one() << 148) + 1) / 17,
+  ];
+  for prime in primes {
+    assert!(prime.is_probable_prime(10), "{prime}");
+    assert!(prime.is_prime_bpsw(), "{prime}");
+  }
+}
+
+#[test]
+fn test_next_prime() {
+  let primes: Vec<u64> =
+    (0..3000).filter(|value| is_prime_naive(*value)).collect();
+
+  for value in 0..2900u64 {
+    let next = primes.iter().find(|prime| **prime > value).unwrap();
+    assert_eq!(BigInt::from(value).next_prime(), BigInt::from(*next));
+
+    let prev = primes.iter().rev().find(|prime| **prime < value);
+    assert_eq!(
+      BigInt::from(value).prev_prime(),
+      prev.map(|p| BigInt::from(*p))
+    );
+  }
+
+  assert_eq!(BigInt::from(-10i64).next_prime(), BigInt::from(2u32));
+  assert_eq!(BigInt::from(-10i64).prev_prime(), None);
+
+  let found: Vec<BigInt> = BigInt::from(100u32).primes_from().take(5).collect();
+  let expected: Vec<BigInt> =
+    [101u32, 103, 107, 109, 113].map(BigInt::from).to_vec();
+  assert_eq!(found, expected);
+
+  let prime = mersenne(89);
+  assert_eq!((&prime - 1).next_prime(), prime);
+  assert_eq!((&prime + 1).prev_prime(), Some(prime.clone()));
+
+  let next = prime.next_prime();
+  assert!(next > prime && next.is_prime_bpsw());
+  let mut value = &prime + 1;
+  while value < next {
+    assert!(!value.is_prime_bpsw());
+    value += 1;
+  }
+  assert_eq!(next.prev_prime(), Some(prime));
+
+  let start = &BigInt::one() << 256;
+  let primes: Vec<BigInt> = start.primes_from().take(3).collect();
+  assert!(primes.windows(2).all(|pair| pair[0] < pair[1]));
+  assert_eq!(primes[0], (&start - 1).next_prime());
+  assert_eq!(primes[2].prev_prime(), Some(primes[1].clone()));
+}
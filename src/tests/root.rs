@@ -0,0 +1,61 @@
+use num::Integer;
+
+use super::helpers::{to_num, XorShift};
+use crate::bigint::{BigInt, BigIntError};
+
+#[test]
+fn test_sqrt() {
+  let mut rng = XorShift::new(0x57);
+
+  for magnitude in [1, 2, 3, 10, 77, 300] {
+    let value = rng.bigint(magnitude).abs();
+    let (root, remainder) = value.sqrt_rem().unwrap();
+
+    assert_eq!(to_num(&root), to_num(&value).sqrt());
+    assert_eq!(&root.square() + &remainder, value);
+  }
+
+  let square = BigInt::from(u64::MAX).square();
+  assert_eq!(square.sqrt(), Ok(BigInt::from(u64::MAX)));
+  assert_eq!((&square - 1).sqrt(), Ok(BigInt::from(u64::MAX - 1)));
+  assert_eq!(BigInt::zero().sqrt(), Ok(BigInt::zero()));
+  assert_eq!(
+    BigInt::negative_one().sqrt(),
+    Err(BigIntError::NegativeRadicand)
+  );
+}
+
+#[test]
+fn test_nth_root() {
+  let mut rng = XorShift::new(0x6e);
+
+  for (magnitude, n) in [(1, 3), (4, 3), (9, 5), (40, 7), (100, 33), (2, 90)] {
+    let value = rng.bigint(magnitude);
+    if value.sign.is_negative() && n.is_even() {
+      continue;
+    }
+
+    // `num` truncates toward zero, so step the inexact negative roots down.
+    let mut expected = to_num(&value).nth_root(n);
+    if expected.pow(n) != to_num(&value) && value.sign.is_negative() {
+      expected -= 1;
+    }
+    assert_eq!(to_num(&value.nth_root(n).unwrap()), expected);
+  }
+
+  let base = BigInt::from(123456789u32);
+  let cube = base.pow(3);
+  assert_eq!(cube.nth_root(3), Ok(base.clone()));
+  assert_eq!((&cube - 1).nth_root(3), Ok(&base - 1));
+  assert_eq!(BigInt::from(-27i64).nth_root(3), Ok(BigInt::from(-3i64)));
+  assert_eq!(BigInt::from(-28i64).nth_root(3), Ok(BigInt::from(-4i64)));
+  let (mut below, mut floor) = (&cube + 1, &base + 1);
+  below.negate();
+  floor.negate();
+  assert_eq!(below.nth_root(3), Ok(floor));
+  assert_eq!(
+    BigInt::from(-16i64).nth_root(4),
+    Err(BigIntError::NegativeRadicand)
+  );
+  assert_eq!(BigInt::one().nth_root(0), Err(BigIntError::ZeroRootDegree));
+}
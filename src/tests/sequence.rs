@@ -0,0 +1,86 @@
+use crate::bigint::{BigInt, BigIntError};
+
+#[test]
+fn test_fibonacci_lucas() {
+  let (mut fibonacci, mut next_fibonacci) = (BigInt::zero(), BigInt::one());
+  let (mut lucas, mut next_lucas) = (BigInt::from(2u32), BigInt::one());
+
+  for n in 0..1000 {
+    assert_eq!(BigInt::fibonacci(n), fibonacci, "F({n})");
+    assert_eq!(BigInt::lucas(n), lucas, "L({n})");
+
+    (fibonacci, next_fibonacci) =
+      (next_fibonacci.clone(), &fibonacci + &next_fibonacci);
+    (lucas, next_lucas) = (next_lucas.clone(), &lucas + &next_lucas);
+  }
+
+  // `F(2n) = F(n) L(n)` and `L(n)^2 - 5F(n)^2 = 4(-1)^n` far past the loop.
+  for n in [12345, 100000] {
+    let (f, l) = (BigInt::fibonacci(n), BigInt::lucas(n));
+    assert_eq!(BigInt::fibonacci(2 * n), &f * &l);
+    assert_eq!(
+      &l.square() - &(&f.square() * 5),
+      BigInt::from(4 * (1 - 2 * (n as i64 & 1)))
+    );
+  }
+}
+
+#[test]
+fn test_lucas_sequence() {
+  for (p, q, modulus) in [
+    (1i64, -1i64, 1000003i64),
+    (3, 2, 1 << 20),
+    (-4, 7, 999),
+    (5, -3, 2),
+    (2, 1, 1),
+  ] {
+    let (big_p, big_q, big_modulus) =
+      (BigInt::from(p), BigInt::from(q), BigInt::from(modulus));
+
+    let (mut u, mut next_u) = (0, 1i64.rem_euclid(modulus));
+    let (mut v, mut next_v) = (2i64.rem_euclid(modulus), p.rem_euclid(modulus));
+    for n in 0..300 {
+      let (big_u, big_v) = BigInt::lucas_sequence(
+        &big_p,
+        &big_q,
+        &BigInt::from(n as u32),
+        &big_modulus,
+      )
+      .unwrap();
+      assert_eq!(big_u, BigInt::from(u), "U_{n}({p}, {q})");
+      assert_eq!(big_v, BigInt::from(v), "V_{n}({p}, {q})");
+
+      (u, next_u) = (next_u, (p * next_u - q * u).rem_euclid(modulus));
+      (v, next_v) = (next_v, (p * next_v - q * v).rem_euclid(modulus));
+    }
+  }
+
+  // Agrees with the plain Fibonacci numbers, and `U_(p + 1) = 0 (mod p)` for
+  // a prime `p` with `(5 / p) = -1`.
+  let prime = &(&BigInt::one() << 127) - 1;
+  let n = BigInt::from(5000u32);
+  let (u, v) =
+    BigInt::lucas_sequence(&BigInt::one(), &BigInt::negative_one(), &n, &prime)
+      .unwrap();
+  assert_eq!(u, BigInt::fibonacci(5000).rem_euclid(&prime));
+  assert_eq!(v, BigInt::lucas(5000).rem_euclid(&prime));
+
+  let (u, _) = BigInt::lucas_sequence(
+    &BigInt::one(),
+    &BigInt::negative_one(),
+    &(&prime + 1),
+    &prime,
+  )
+  .unwrap();
+  assert!(u.is_zero());
+
+  let one = BigInt::one();
+  assert_eq!(
+    BigInt::lucas_sequence(&one, &one, &one, &BigInt::zero()),
+    Err(BigIntError::ZeroModulus)
+  );
+  assert_eq!(
+    BigInt::lucas_sequence(&one, &one, &BigInt::negative_one(), &one),
+    Err(BigIntError::NegativeIndex)
+  );
+}
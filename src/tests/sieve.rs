@@ -0,0 +1,58 @@
+use crate::{
+  bigint::BigInt,
+  sieve::{prime_pi, primes_up_to, primorial, SMALL_PRIMES},
+};
+
+fn is_prime_naive(value: u64) -> bool {
+  value >= 2
+    && (2..)
+      .take_while(|p| p * p <= value)
+      .all(|p| !value.is_multiple_of(p))
+}
+
+#[test]
+fn test_primes_up_to() {
+  for limit in [0, 1, 2, 3, 4, 9, 25, 65535, 65537, 200000] {
+    let expected: Vec<u64> =
+      (0..=limit).filter(|v| is_prime_naive(*v)).collect();
+    assert_eq!(primes_up_to(limit).collect::<Vec<_>>(), expected, "{limit}");
+  }
+
+  let small: Vec<u64> = SMALL_PRIMES.iter().map(|p| *p as u64).collect();
+  assert_eq!(primes_up_to(251).collect::<Vec<_>>(), small);
+
+  // Crosses many segments.
+  let mut primes = primes_up_to(10_000_000);
+  assert_eq!(primes.by_ref().count(), 664579);
+  assert_eq!(primes.next(), None);
+  assert_eq!(primes_up_to(10_000_000).last(), Some(9999991));
+}
+
+#[test]
+fn test_prime_pi() {
+  for n in 0..2000 {
+    assert_eq!(prime_pi(n), primes_up_to(n).count() as u64, "{n}");
+  }
+
+  for (exponent, expected) in [
+    (3, 168),
+    (4, 1229),
+    (6, 78498),
+    (9, 50847534),
+    (10, 455052511),
+  ] {
+    assert_eq!(prime_pi(10u64.pow(exponent)), expected, "10^{exponent}");
+  }
+}
+
+#[test]
+fn test_primorial() {
+  assert_eq!(primorial(0), BigInt::one());
+  assert_eq!(primorial(1), BigInt::one());
+  assert_eq!(primorial(30), BigInt::from(6469693230u64));
+  assert_eq!(primorial(31), BigInt::from(200560490130u64));
+
+  let expected = primes_up_to(5000)
+    .fold(BigInt::one(), |product, prime| &product * prime as u32);
+  assert_eq!(primorial(5000), expected);
+}
@@ -0,0 +1,75 @@
+use super::helpers::XorShift;
+use crate::bigint::{BigInt, BigIntError};
+
+#[test]
+fn test_jacobi() {
+  let mut rng = XorShift::new(0x15);
+  let p = &(&BigInt::one() << 127) - 1;
+  let q = &(&BigInt::one() << 89) - 1;
+  let pq = &p * &q;
+
+  for magnitude in [1, 2, 4, 7] {
+    let value = rng.bigint(magnitude);
+
+    // Euler's criterion: `a^((p - 1) / 2) = (a / p) (mod p)`.
+    let power = value.modpow(&(&(&p - 1) >> 1), &p).unwrap();
+    let expected = match power == BigInt::one() {
+      true => 1,
+      false => -1,
+    };
+    assert_eq!(value.legendre(&p), Ok(expected));
+
+    assert_eq!(
+      value.jacobi(&pq).unwrap(),
+      value.jacobi(&p).unwrap() * value.jacobi(&q).unwrap()
+    );
+  }
+
+  assert_eq!(BigInt::from(1001u32).jacobi(&BigInt::from(9907u32)), Ok(-1));
+  assert_eq!(BigInt::from(19u32).jacobi(&BigInt::from(45u32)), Ok(1));
+  assert_eq!(BigInt::from(8u32).jacobi(&BigInt::from(21u32)), Ok(-1));
+  assert_eq!(BigInt::from(-30i64).jacobi(&BigInt::from(7u32)), Ok(-1));
+  assert_eq!(BigInt::from(6u32).jacobi(&BigInt::from(15u32)), Ok(0));
+  assert_eq!(q.jacobi(&pq), Ok(0));
+  assert_eq!(
+    BigInt::one().jacobi(&BigInt::from(4u32)),
+    Err(BigIntError::EvenModulus)
+  );
+  assert_eq!(
+    BigInt::one().jacobi(&BigInt::from(-3i64)),
+    Err(BigIntError::NegativeModulus)
+  );
+  assert_eq!(
+    BigInt::one().jacobi(&BigInt::zero()),
+    Err(BigIntError::ZeroModulus)
+  );
+}
+
+#[test]
+fn test_kronecker() {
+  let cases: [(i64, i64, i8); 10] = [
+    (5, 21, 1),
+    (-1, -1, -1),
+    (-5, -12, -1),
+    (3, -8, -1),
+    (6, 10, 0),
+    (-7, 0, 0),
+    (-1, 0, 1),
+    (2, -9, 1),
+    (30, -7, 1),
+    (12345678901234567, -987654321098765430, -1),
+  ];
+
+  for (value, n, expected) in cases {
+    assert_eq!(
+      BigInt::from(value).kronecker(&BigInt::from(n)),
+      expected,
+      "({value} / {n})"
+    );
+  }
+
+  let mut rng = XorShift::new(0x4b);
+  let odd = &(&rng.bigint(5).abs() << 1) + 1;
+  let value = rng.bigint(6);
+  assert_eq!(value.kronecker(&odd), value.jacobi(&odd).unwrap());
+}
@@ -124,3 +124,37 @@ where
     lhs.push(I::one());
   }
 }
+
+#[inline(always)]
+pub fn digital_add_at_offset_u32(
+  lhs: &mut Vec<u32>,
+  rhs: &[u32],
+  offset: usize,
+) {
+  if lhs.len() < offset + rhs.len() {
+    lhs.resize(offset + rhs.len(), 0);
+  }
+
+  let mut carry = false;
+  for (idx, digit) in rhs.iter().enumerate() {
+    let (before_carry, first_carry) = lhs[offset + idx].overflowing_add(*digit);
+    let (after_carry, second_carry) =
+      before_carry.overflowing_add(carry as u32);
+
+    lhs[offset + idx] = after_carry;
+    carry = first_carry || second_carry;
+  }
+
+  let mut current_idx = offset + rhs.len();
+  while carry {
+    if current_idx == lhs.len() {
+      lhs.push(1);
+      break;
+    }
+
+    let (after_carry, next_carry) = lhs[current_idx].overflowing_add(1);
+    lhs[current_idx] = after_carry;
+    carry = next_carry;
+    current_idx += 1;
+  }
+}
@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+
+use crate::utils::{
+  digital_cmp, digital_multiply_dispatch_u32, digital_subtract_in_place_u32,
+  digital_trim_zeroes, MultiplyCrossovers,
+};
+
+/// Precomputed `mu = floor(2^(64k) / m)` for a fixed `k`-limb modulus `m`,
+/// which turns reduction into two multiplications and a few subtractions.
+#[derive(Debug, Clone)]
+pub struct BarrettReducer {
+  modulus: Vec<u32>,
+  reciprocal: Vec<u32>,
+  crossovers: MultiplyCrossovers,
+}
+
+impl BarrettReducer {
+  /// `reciprocal` must be `floor(2^(64k) / modulus)` for the `k`-limb,
+  /// nonzero `modulus`.
+  #[inline]
+  pub(crate) fn with_reciprocal(
+    mut modulus: Vec<u32>,
+    mut reciprocal: Vec<u32>,
+    crossovers: MultiplyCrossovers,
+  ) -> Self {
+    digital_trim_zeroes(&mut modulus);
+    digital_trim_zeroes(&mut reciprocal);
+
+    Self {
+      modulus,
+      reciprocal,
+      crossovers,
+    }
+  }
+
+  #[inline(always)]
+  pub fn modulus_digits(&self) -> &[u32] {
+    &self.modulus
+  }
+
+  #[inline]
+  pub fn reduce_digits(&self, digits: &[u32]) -> Vec<u32> {
+    let magnitude = self.modulus.len();
+    let mut remaining = digits.to_vec();
+    digital_trim_zeroes(&mut remaining);
+
+    // Values wider than `2k` limbs are folded from the top, `k` limbs at a
+    // time, like schoolbook division in base `2^(32k)`.
+    while remaining.len() > 2 * magnitude {
+      let split = remaining.len() - 2 * magnitude;
+      let high = self.reduce_window(&remaining[split..]);
+      remaining.truncate(split);
+      remaining.extend(high);
+      digital_trim_zeroes(&mut remaining);
+    }
+
+    self.reduce_window(&remaining)
+  }
+
+  #[inline(always)]
+  pub fn mul_mod_digits(&self, lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    self.reduce_digits(&digital_multiply_dispatch_u32(
+      lhs,
+      rhs,
+      self.crossovers,
+    ))
+  }
+
+  // Reduces a value of at most `2k` limbs.
+  #[inline]
+  fn reduce_window(&self, digits: &[u32]) -> Vec<u32> {
+    let magnitude = self.modulus.len();
+
+    if digital_cmp(digits, &self.modulus) == Ordering::Less {
+      let mut result = digits.to_vec();
+      digital_trim_zeroes(&mut result);
+      return result;
+    }
+
+    let estimate = digital_multiply_dispatch_u32(
+      &digits[magnitude - 1..],
+      &self.reciprocal,
+      self.crossovers,
+    );
+
+    let mut result = digits.to_vec();
+    if estimate.len() > magnitude + 1 {
+      let quotient = &estimate[magnitude + 1..];
+      let product =
+        digital_multiply_dispatch_u32(quotient, &self.modulus, self.crossovers);
+      digital_subtract_in_place_u32(&mut result, &product);
+    }
+    digital_trim_zeroes(&mut result);
+
+    // The estimated quotient falls short of the true one by at most two.
+    while digital_cmp(&result, &self.modulus) != Ordering::Less {
+      digital_subtract_in_place_u32(&mut result, &self.modulus);
+    }
+
+    result
+  }
+}
@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+
+use super::digital_scalar_divide_u32;
+use crate::utils::{
+  digital_cmp, digital_shift_left_u32, digital_shift_right_u32,
+  digital_trim_zeroes,
+};
+
+#[inline]
+pub fn digital_divide_with_rem_u32(
+  lhs: &[u32],
+  rhs: &[u32],
+) -> (Vec<u32>, Vec<u32>) {
+  let mut dividend = lhs.to_vec();
+  let mut divisor = rhs.to_vec();
+  digital_trim_zeroes(&mut dividend);
+  digital_trim_zeroes(&mut divisor);
+
+  if divisor == [0] {
+    panic!("Attempt to divide by zero");
+  }
+
+  match digital_cmp(&dividend, &divisor) {
+    Ordering::Less => return (vec![0], dividend),
+    Ordering::Equal => return (vec![1], vec![0]),
+    Ordering::Greater => {}
+  }
+
+  if divisor.len() == 1 {
+    let (mut quotient, rem) = digital_scalar_divide_u32(&dividend, divisor[0]);
+    digital_trim_zeroes(&mut quotient);
+    return (quotient, vec![rem]);
+  }
+
+  let shift = divisor[divisor.len() - 1].leading_zeros() as usize;
+  let normalized_divisor = digital_shift_left_u32(&divisor, shift);
+  let mut remainder = digital_shift_left_u32(&dividend, shift);
+  remainder.resize(dividend.len() + 1, 0);
+
+  let mut quotient =
+    digital_divide_normalized_in_place_u32(&mut remainder, &normalized_divisor);
+  digital_trim_zeroes(&mut quotient);
+
+  let remainder = digital_shift_right_u32(&remainder[0..divisor.len()], shift);
+
+  (quotient, remainder)
+}
+
+#[inline(always)]
+pub fn digital_divide_u32(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+  digital_divide_with_rem_u32(lhs, rhs).0
+}
+
+#[inline(always)]
+pub fn digital_rem_u32(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+  digital_divide_with_rem_u32(lhs, rhs).1
+}
+
+// Knuth's Algorithm D. The divisor must have at least two digits and its top
+// bit set, and the dividend needs one spare high digit beyond the divisor's
+// top digit. On return the low `rhs.len()` digits of `lhs` hold the remainder.
+#[inline]
+pub fn digital_divide_normalized_in_place_u32(
+  lhs: &mut [u32],
+  rhs: &[u32],
+) -> Vec<u32> {
+  let divisor_magnitude = rhs.len();
+  let quotient_magnitude = lhs.len() - divisor_magnitude;
+
+  let mut quotient = vec![0; quotient_magnitude];
+
+  let divisor_top = rhs[divisor_magnitude - 1] as u64;
+  let divisor_next = rhs[divisor_magnitude - 2] as u64;
+
+  for offset in (0..quotient_magnitude).rev() {
+    let top_idx = offset + divisor_magnitude;
+    let numerator = ((lhs[top_idx] as u64) << 32) | lhs[top_idx - 1] as u64;
+
+    let mut quot_estimate = numerator / divisor_top;
+    let mut rem_estimate = numerator % divisor_top;
+
+    while quot_estimate > u32::MAX as u64
+      || quot_estimate * divisor_next
+        > ((rem_estimate << 32) | lhs[top_idx - 2] as u64)
+    {
+      quot_estimate -= 1;
+      rem_estimate += divisor_top;
+      if rem_estimate > u32::MAX as u64 {
+        break;
+      }
+    }
+
+    let mut carry = 0u64;
+    let mut borrow = false;
+    for (idx, digit) in rhs.iter().enumerate() {
+      let product = quot_estimate * *digit as u64 + carry;
+      carry = product >> 32;
+
+      let (after_sub, sub_borrow) =
+        lhs[offset + idx].overflowing_sub(product as u32);
+      let (after_borrow, borrow_borrow) =
+        after_sub.overflowing_sub(borrow as u32);
+
+      lhs[offset + idx] = after_borrow;
+      borrow = sub_borrow || borrow_borrow;
+    }
+
+    let (after_sub, sub_borrow) = lhs[top_idx].overflowing_sub(carry as u32);
+    let (after_borrow, borrow_borrow) =
+      after_sub.overflowing_sub(borrow as u32);
+    lhs[top_idx] = after_borrow;
+
+    if sub_borrow || borrow_borrow {
+      quot_estimate -= 1;
+
+      let mut carry = 0u64;
+      for (idx, digit) in rhs.iter().enumerate() {
+        let sum = lhs[offset + idx] as u64 + *digit as u64 + carry;
+        lhs[offset + idx] = sum as u32;
+        carry = sum >> 32;
+      }
+      lhs[top_idx] = lhs[top_idx].wrapping_add(carry as u32);
+    }
+
+    quotient[offset] = quot_estimate as u32;
+  }
+
+  quotient
+}
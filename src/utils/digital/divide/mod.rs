@@ -1,5 +1,11 @@
+mod barrett;
+mod long;
+
 use std::cmp::Ordering;
 
+pub use barrett::*;
+pub use long::*;
+
 #[inline(always)]
 pub fn digital_scalar_divide_in_place_u32(lhs: &mut [u32], rhs: u32) -> u32 {
   if rhs == 0 {
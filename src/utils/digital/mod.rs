@@ -1,14 +1,17 @@
 mod add;
 mod cmp;
 mod divide;
+mod montgomery;
 mod multiply;
+mod shift;
 mod sign;
 mod subtract;
+mod trim;
 mod wrap;
 
 pub use self::{
-  add::*, cmp::digital_cmp, divide::*, multiply::*, sign::Sign, subtract::*,
-  wrap::DigitalWrap,
+  add::*, cmp::digital_cmp, divide::*, montgomery::*, multiply::*, shift::*,
+  sign::Sign, subtract::*, trim::digital_trim_zeroes, wrap::DigitalWrap,
 };
 
 pub trait Digital {
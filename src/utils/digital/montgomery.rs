@@ -0,0 +1,58 @@
+use std::cmp::Ordering;
+
+use super::{digital_cmp, digital_subtract_in_place_u32, digital_trim_zeroes};
+
+// Computes `-modulus^-1 mod 2^32` for an odd lowest limb. Each Newton step
+// doubles the number of correct low bits, starting from one.
+#[inline]
+pub fn digital_montgomery_inverse_u32(low_digit: u32) -> u32 {
+  let mut inverse = 1u32;
+
+  for _ in 0..5 {
+    inverse =
+      inverse.wrapping_mul(2u32.wrapping_sub(low_digit.wrapping_mul(inverse)));
+  }
+
+  inverse.wrapping_neg()
+}
+
+// REDC: given `value < modulus * 2^(32n)` for an `n`-limb odd modulus,
+// returns `value * 2^(-32n) mod modulus` without dividing.
+#[inline]
+pub fn digital_montgomery_reduce_u32(
+  mut value: Vec<u32>,
+  modulus: &[u32],
+  inverse: u32,
+) -> Vec<u32> {
+  let magnitude = modulus.len();
+  debug_assert!(value.len() <= 2 * magnitude);
+  value.resize(2 * magnitude + 1, 0);
+
+  for idx in 0..magnitude {
+    let factor = value[idx].wrapping_mul(inverse) as u64;
+    let mut carry = 0;
+
+    for (offset, digit) in modulus.iter().enumerate() {
+      let sum = factor * *digit as u64 + value[idx + offset] as u64 + carry;
+      value[idx + offset] = sum as u32;
+      carry = sum >> 32;
+    }
+
+    let mut position = idx + magnitude;
+    while carry != 0 {
+      let sum = value[position] as u64 + carry;
+      value[position] = sum as u32;
+      carry = sum >> 32;
+      position += 1;
+    }
+  }
+
+  let mut result = value.split_off(magnitude);
+  digital_trim_zeroes(&mut result);
+
+  if digital_cmp(&result, modulus) != Ordering::Less {
+    digital_subtract_in_place_u32(&mut result, modulus);
+  }
+
+  result
+}
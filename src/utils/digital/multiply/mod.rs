@@ -1,9 +1,92 @@
 mod fixed_width;
+mod ntt;
+mod square;
+mod toom;
 mod variable_width;
 
 pub use fixed_width::*;
+pub use ntt::*;
+pub use square::*;
+pub use toom::*;
 pub use variable_width::*;
 
+use super::{digital_add_at_offset_u32, digital_trim_zeroes};
+
+#[derive(Debug, Clone, Copy)]
+pub struct MultiplyCrossovers {
+  pub karatsuba: usize,
+  pub toom_3: usize,
+  pub toom_4: usize,
+  pub ntt: usize,
+}
+
+#[inline]
+pub fn digital_multiply_dispatch_u32(
+  lhs: &[u32],
+  rhs: &[u32],
+  crossovers: MultiplyCrossovers,
+) -> Vec<u32> {
+  let (larger, smaller) = if lhs.len() >= rhs.len() {
+    (lhs, rhs)
+  } else {
+    (rhs, lhs)
+  };
+  let smaller_magnitude = smaller.len();
+
+  let mut result = if smaller_magnitude < crossovers.karatsuba {
+    digital_multiply_u32(lhs, rhs)
+  } else if smaller_magnitude >= crossovers.ntt
+    && larger.len() + smaller_magnitude <= ntt_max_magnitude()
+  {
+    ntt_mul(lhs, rhs)
+  } else if larger.len() >= 2 * smaller_magnitude {
+    // Every tier splits both operands at the same point, so a lopsided product
+    // is cut into balanced pieces that are summed back together.
+    let mut result = vec![0; larger.len() + smaller_magnitude];
+    for (idx, chunk) in larger.chunks(smaller_magnitude).enumerate() {
+      digital_add_at_offset_u32(
+        &mut result,
+        &digital_multiply_dispatch_u32(chunk, smaller, crossovers),
+        idx * smaller_magnitude,
+      );
+    }
+    result
+  } else if smaller_magnitude >= crossovers.toom_4 {
+    toom_4_mul(lhs, rhs, crossovers)
+  } else if smaller_magnitude >= crossovers.toom_3 {
+    toom_3_mul(lhs, rhs, crossovers)
+  } else {
+    karatsuba_mul(lhs, rhs, crossovers.karatsuba)
+  };
+
+  digital_trim_zeroes(&mut result);
+  result
+}
+
+#[inline]
+pub fn digital_square_dispatch_u32(
+  digits: &[u32],
+  crossovers: MultiplyCrossovers,
+) -> Vec<u32> {
+  let magnitude = digits.len();
+
+  let mut result = if magnitude < crossovers.karatsuba {
+    digital_square_u32(digits)
+  } else if magnitude >= crossovers.ntt && 2 * magnitude <= ntt_max_magnitude()
+  {
+    ntt_mul(digits, digits)
+  } else if magnitude >= crossovers.toom_4 {
+    toom_4_mul(digits, digits, crossovers)
+  } else if magnitude >= crossovers.toom_3 {
+    toom_3_mul(digits, digits, crossovers)
+  } else {
+    karatsuba_square(digits, crossovers.karatsuba)
+  };
+
+  digital_trim_zeroes(&mut result);
+  result
+}
+
 #[inline(always)]
 fn higher_order_multiply(lhs: u32, rhs: u32) -> u64 {
   lhs as u64 * rhs as u64
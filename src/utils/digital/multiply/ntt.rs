@@ -0,0 +1,194 @@
+// Three NTT-friendly primes of the form k * 2^m + 1, each with 3 as a
+// primitive root. Their product exceeds 2^86, enough to recover any
+// coefficient of a product of two operands of at most 2^22 u32 digits.
+const PRIME_ONE: u64 = 998_244_353;
+const PRIME_TWO: u64 = 167_772_161;
+const PRIME_THREE: u64 = 469_762_049;
+const PRIMITIVE_ROOT: u64 = 3;
+
+// The smallest two-adic order among the primes above bounds the transform.
+const MAX_TRANSFORM_MAGNITUDE: usize = 1 << 23;
+
+#[inline(always)]
+pub fn ntt_max_magnitude() -> usize {
+  MAX_TRANSFORM_MAGNITUDE
+}
+
+#[inline]
+pub fn ntt_mul(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+  if lhs.is_empty() || rhs.is_empty() {
+    return vec![0];
+  }
+
+  let product_magnitude = lhs.len() + rhs.len();
+  if product_magnitude - 1 > MAX_TRANSFORM_MAGNITUDE
+    || usize::min(lhs.len(), rhs.len()) > MAX_TRANSFORM_MAGNITUDE / 2
+  {
+    panic!("Operands too large for number-theoretic transform");
+  }
+
+  let transform_magnitude = (product_magnitude - 1).next_power_of_two();
+
+  let residues_one =
+    convolve::<PRIME_ONE>(lhs, rhs, transform_magnitude, lhs == rhs);
+  let residues_two =
+    convolve::<PRIME_TWO>(lhs, rhs, transform_magnitude, lhs == rhs);
+  let residues_three =
+    convolve::<PRIME_THREE>(lhs, rhs, transform_magnitude, lhs == rhs);
+
+  // Garner's algorithm recovers each coefficient from its three residues.
+  let one_inverse_mod_two = power_mod::<PRIME_TWO>(PRIME_ONE, PRIME_TWO - 2);
+  let one_two_mod_three = PRIME_ONE % PRIME_THREE * PRIME_TWO % PRIME_THREE;
+  let one_two_inverse_mod_three =
+    power_mod::<PRIME_THREE>(one_two_mod_three, PRIME_THREE - 2);
+  let one_two = PRIME_ONE as u128 * PRIME_TWO as u128;
+
+  let mut result = Vec::with_capacity(product_magnitude);
+  let mut carry = 0u128;
+
+  for idx in 0..product_magnitude - 1 {
+    let first = residues_one[idx];
+    let second = residues_two[idx];
+    let third = residues_three[idx];
+
+    let first_step = (second + PRIME_TWO - first % PRIME_TWO) % PRIME_TWO
+      * one_inverse_mod_two
+      % PRIME_TWO;
+    let partial = first + PRIME_ONE * first_step;
+
+    let second_step = (third + PRIME_THREE - partial % PRIME_THREE)
+      % PRIME_THREE
+      * one_two_inverse_mod_three
+      % PRIME_THREE;
+
+    carry += partial as u128 + one_two * second_step as u128;
+    result.push(carry as u32);
+    carry >>= 32;
+  }
+
+  while carry > 0 {
+    result.push(carry as u32);
+    carry >>= 32;
+  }
+
+  result
+}
+
+#[inline]
+fn convolve<const PRIME: u64>(
+  lhs: &[u32],
+  rhs: &[u32],
+  transform_magnitude: usize,
+  squaring: bool,
+) -> Vec<u64> {
+  let reduce = |digits: &[u32]| {
+    let mut values: Vec<u64> =
+      digits.iter().map(|digit| *digit as u64 % PRIME).collect();
+    values.resize(transform_magnitude, 0);
+    values
+  };
+
+  let mut left = reduce(lhs);
+  transform::<PRIME>(&mut left, false);
+
+  if squaring {
+    for value in left.iter_mut() {
+      *value = *value * *value % PRIME;
+    }
+  } else {
+    let mut right = reduce(rhs);
+    transform::<PRIME>(&mut right, false);
+
+    for (value, other) in left.iter_mut().zip(right.iter()) {
+      *value = *value * other % PRIME;
+    }
+  }
+
+  transform::<PRIME>(&mut left, true);
+  left
+}
+
+#[inline]
+fn transform<const PRIME: u64>(values: &mut [u64], invert: bool) {
+  let magnitude = values.len();
+
+  let mut reversed = 0;
+  for idx in 1..magnitude {
+    let mut bit = magnitude >> 1;
+    while reversed & bit != 0 {
+      reversed ^= bit;
+      bit >>= 1;
+    }
+    reversed |= bit;
+
+    if idx < reversed {
+      values.swap(idx, reversed);
+    }
+  }
+
+  let mut twiddles = Vec::with_capacity(magnitude / 2);
+  let mut span = 2;
+
+  while span <= magnitude {
+    let half = span / 2;
+
+    let mut root =
+      power_mod::<PRIME>(PRIMITIVE_ROOT, (PRIME - 1) / span as u64);
+    if invert {
+      root = power_mod::<PRIME>(root, PRIME - 2);
+    }
+
+    twiddles.clear();
+    let mut twiddle = 1;
+    for _ in 0..half {
+      twiddles.push(twiddle);
+      twiddle = twiddle * root % PRIME;
+    }
+
+    for block in values.chunks_exact_mut(span) {
+      let (low, high) = block.split_at_mut(half);
+      for ((left, right), twiddle) in
+        low.iter_mut().zip(high.iter_mut()).zip(twiddles.iter())
+      {
+        let even = *left;
+        let odd = *right * twiddle % PRIME;
+
+        *left = if even + odd >= PRIME {
+          even + odd - PRIME
+        } else {
+          even + odd
+        };
+        *right = if even >= odd {
+          even - odd
+        } else {
+          even + PRIME - odd
+        };
+      }
+    }
+
+    span <<= 1;
+  }
+
+  if invert {
+    let magnitude_inverse = power_mod::<PRIME>(magnitude as u64, PRIME - 2);
+    for value in values.iter_mut() {
+      *value = *value * magnitude_inverse % PRIME;
+    }
+  }
+}
+
+#[inline]
+fn power_mod<const PRIME: u64>(mut base: u64, mut exponent: u64) -> u64 {
+  let mut result = 1;
+  base %= PRIME;
+
+  while exponent > 0 {
+    if exponent & 1 == 1 {
+      result = result * base % PRIME;
+    }
+    base = base * base % PRIME;
+    exponent >>= 1;
+  }
+
+  result
+}
@@ -0,0 +1,97 @@
+use super::higher_order_multiply;
+use crate::utils::{digital_add_at_offset_u32, digital_subtract_in_place_u32};
+
+// Each cross product `x_i * x_j` appears twice in a square, so only the
+// products above the diagonal are formed, doubled, and then joined by the
+// diagonal squares.
+#[inline(always)]
+pub fn digital_square_u32(digits: &[u32]) -> Vec<u32> {
+  let magnitude = digits.len();
+
+  match magnitude {
+    0 => vec![0],
+    1 => {
+      let high_res = higher_order_multiply(digits[0], digits[0]);
+      vec![high_res as u32, (high_res >> 32) as u32]
+    }
+    _ => {
+      let mut result = vec![0; 2 * magnitude];
+
+      for (idx, digit) in digits.iter().enumerate() {
+        let mut carry = 0;
+
+        for (offset, other_digit) in digits.iter().enumerate().skip(idx + 1) {
+          let product = higher_order_multiply(*digit, *other_digit)
+            + result[idx + offset] as u64
+            + carry;
+
+          result[idx + offset] = product as u32;
+          carry = product >> 32;
+        }
+
+        result[idx + magnitude] = carry as u32;
+      }
+
+      let mut shifted_out = 0;
+      for el in result.iter_mut() {
+        let next_shifted_out = *el >> 31;
+        *el = (*el << 1) | shifted_out;
+        shifted_out = next_shifted_out;
+      }
+
+      let mut carry = 0;
+      for (idx, digit) in digits.iter().enumerate() {
+        let square = higher_order_multiply(*digit, *digit);
+
+        let low = result[2 * idx] as u64 + (square as u32) as u64 + carry;
+        result[2 * idx] = low as u32;
+
+        let high = result[2 * idx + 1] as u64 + (square >> 32) + (low >> 32);
+        result[2 * idx + 1] = high as u32;
+        carry = high >> 32;
+      }
+
+      result
+    }
+  }
+}
+
+#[inline(always)]
+pub fn karatsuba_square(digits: &[u32], crossover: usize) -> Vec<u32> {
+  let magnitude = digits.len();
+  if magnitude < 2 {
+    return digital_square_u32(digits);
+  }
+
+  let should_k_recursive = magnitude >= crossover;
+  let half = magnitude >> 1;
+
+  let a = &digits[half..];
+  let b = &digits[..half];
+
+  let square = |part: &[u32]| {
+    if should_k_recursive {
+      karatsuba_square(part, crossover)
+    } else {
+      digital_square_u32(part)
+    }
+  };
+
+  let digital_aa = square(a);
+  let digital_bb = square(b);
+
+  let mut a_plus_b = a.to_vec();
+  digital_add_at_offset_u32(&mut a_plus_b, b, 0);
+
+  let mut two_ab = square(&a_plus_b);
+  digital_subtract_in_place_u32(&mut two_ab, &digital_aa);
+  digital_subtract_in_place_u32(&mut two_ab, &digital_bb);
+
+  let mut result = Vec::with_capacity(2 * magnitude);
+  result.extend(digital_bb);
+
+  digital_add_at_offset_u32(&mut result, &two_ab, half);
+  digital_add_at_offset_u32(&mut result, &digital_aa, half * 2);
+
+  result
+}
@@ -0,0 +1,263 @@
+use std::cmp::Ordering;
+
+use super::{digital_multiply_dispatch_u32, digital_square_dispatch_u32};
+use crate::utils::{
+  digital_add_at_offset_u32, digital_cmp, digital_scalar_divide_in_place_u32,
+  digital_scalar_multiply_in_place_u32, digital_shift_left_u32,
+  digital_subtract_in_place_u32, digital_trim_zeroes, MultiplyCrossovers, Sign,
+};
+
+// Toom-Cook evaluation and interpolation pass through negative values, so the
+// intermediate coefficients carry a sign alongside their digits.
+type SignedDigits = (Vec<u32>, Sign);
+
+#[inline]
+pub fn toom_3_mul(
+  lhs: &[u32],
+  rhs: &[u32],
+  crossovers: MultiplyCrossovers,
+) -> Vec<u32> {
+  let part = usize::max(lhs.len(), rhs.len()).div_ceil(3);
+
+  let [a0, a1, a2] = split_parts::<3>(lhs, part);
+  let [b0, b1, b2] = split_parts::<3>(rhs, part);
+
+  let evaluate = |x0: &SignedDigits, x1: &SignedDigits, x2: &SignedDigits| {
+    let even = signed_add(x0, x2);
+    let at_one = signed_add(&even, x1);
+    let at_negative_one = signed_subtract(&even, x1);
+    let at_negative_two = signed_subtract(
+      &signed_shift_left(&signed_add(&at_negative_one, x2), 1),
+      x0,
+    );
+    (at_one, at_negative_one, at_negative_two)
+  };
+
+  let (a_one, a_negative_one, a_negative_two) = evaluate(&a0, &a1, &a2);
+  let (b_one, b_negative_one, b_negative_two) = evaluate(&b0, &b1, &b2);
+
+  let v0 = signed_multiply(&a0, &b0, crossovers);
+  let v1 = signed_multiply(&a_one, &b_one, crossovers);
+  let vm1 = signed_multiply(&a_negative_one, &b_negative_one, crossovers);
+  let vm2 = signed_multiply(&a_negative_two, &b_negative_two, crossovers);
+  let vinf = signed_multiply(&a2, &b2, crossovers);
+
+  // Bodrato's interpolation sequence.
+  let r3 = signed_divide_exact(&signed_subtract(&vm2, &v1), 3);
+  let r1 = signed_divide_exact(&signed_subtract(&v1, &vm1), 2);
+  let r2 = signed_subtract(&vm1, &v0);
+  let r3 = signed_add(
+    &signed_divide_exact(&signed_subtract(&r2, &r3), 2),
+    &signed_shift_left(&vinf, 1),
+  );
+  let r2 = signed_subtract(&signed_add(&r2, &r1), &vinf);
+  let r1 = signed_subtract(&r1, &r3);
+
+  recompose(&[v0, r1, r2, r3, vinf], part)
+}
+
+#[inline]
+pub fn toom_4_mul(
+  lhs: &[u32],
+  rhs: &[u32],
+  crossovers: MultiplyCrossovers,
+) -> Vec<u32> {
+  let part = usize::max(lhs.len(), rhs.len()).div_ceil(4);
+
+  let [a0, a1, a2, a3] = split_parts::<4>(lhs, part);
+  let [b0, b1, b2, b3] = split_parts::<4>(rhs, part);
+
+  let evaluate = |x0: &SignedDigits,
+                  x1: &SignedDigits,
+                  x2: &SignedDigits,
+                  x3: &SignedDigits| {
+    let even_one = signed_add(x0, x2);
+    let odd_one = signed_add(x1, x3);
+    let even_two = signed_add(x0, &signed_shift_left(x2, 2));
+    let odd_two =
+      signed_add(&signed_shift_left(x1, 1), &signed_shift_left(x3, 3));
+    let at_half = signed_add(
+      &signed_add(&signed_shift_left(x0, 3), &signed_shift_left(x1, 2)),
+      &signed_add(&signed_shift_left(x2, 1), x3),
+    );
+
+    (
+      signed_add(&even_one, &odd_one),
+      signed_subtract(&even_one, &odd_one),
+      signed_add(&even_two, &odd_two),
+      signed_subtract(&even_two, &odd_two),
+      at_half,
+    )
+  };
+
+  let (a_one, a_negative_one, a_two, a_negative_two, a_half) =
+    evaluate(&a0, &a1, &a2, &a3);
+  let (b_one, b_negative_one, b_two, b_negative_two, b_half) =
+    evaluate(&b0, &b1, &b2, &b3);
+
+  let c0 = signed_multiply(&a0, &b0, crossovers);
+  let v1 = signed_multiply(&a_one, &b_one, crossovers);
+  let vm1 = signed_multiply(&a_negative_one, &b_negative_one, crossovers);
+  let v2 = signed_multiply(&a_two, &b_two, crossovers);
+  let vm2 = signed_multiply(&a_negative_two, &b_negative_two, crossovers);
+  let vh = signed_multiply(&a_half, &b_half, crossovers);
+  let c6 = signed_multiply(&a3, &b3, crossovers);
+
+  // Split each pair of opposite points into their even and odd coefficient
+  // sums, then solve the two small systems that remain.
+  let even_one = signed_divide_exact(&signed_add(&v1, &vm1), 2);
+  let odd_one = signed_divide_exact(&signed_subtract(&v1, &vm1), 2);
+  let even_two = signed_divide_exact(&signed_add(&v2, &vm2), 2);
+  let odd_two = signed_divide_exact(&signed_subtract(&v2, &vm2), 4);
+
+  let outer = signed_add(&c0, &c6);
+  let c2_plus_c4 = signed_subtract(&even_one, &outer);
+  let c2_plus_4c4 = signed_divide_exact(
+    &signed_subtract(
+      &signed_subtract(&even_two, &c0),
+      &signed_shift_left(&c6, 6),
+    ),
+    4,
+  );
+  let c4 = signed_divide_exact(&signed_subtract(&c2_plus_4c4, &c2_plus_c4), 3);
+  let c2 = signed_subtract(&c2_plus_c4, &c4);
+
+  let half_odd = signed_divide_exact(
+    &signed_subtract(
+      &signed_subtract(&vh, &signed_shift_left(&c0, 6)),
+      &signed_add(
+        &signed_add(&signed_shift_left(&c2, 4), &signed_shift_left(&c4, 2)),
+        &c6,
+      ),
+    ),
+    2,
+  );
+  let c3_plus_5c5 =
+    signed_divide_exact(&signed_subtract(&odd_two, &odd_one), 3);
+  let twelve_c3_plus_15c5 =
+    signed_subtract(&signed_shift_left(&odd_one, 4), &half_odd);
+  let c5 = signed_divide_exact(
+    &signed_subtract(
+      &signed_scalar_multiply(&c3_plus_5c5, 12),
+      &twelve_c3_plus_15c5,
+    ),
+    45,
+  );
+  let c3 = signed_subtract(&c3_plus_5c5, &signed_scalar_multiply(&c5, 5));
+  let c1 = signed_subtract(&signed_subtract(&odd_one, &c3), &c5);
+
+  recompose(&[c0, c1, c2, c3, c4, c5, c6], part)
+}
+
+#[inline]
+fn split_parts<const PARTS: usize>(
+  digits: &[u32],
+  part: usize,
+) -> [SignedDigits; PARTS] {
+  std::array::from_fn(|idx| {
+    let start = usize::min(idx * part, digits.len());
+    let end = usize::min(start + part, digits.len());
+    signed_from_digits(digits[start..end].to_vec(), Sign::Positive)
+  })
+}
+
+#[inline]
+fn recompose(coefficients: &[SignedDigits], part: usize) -> Vec<u32> {
+  let mut result = vec![0; coefficients.len() * part + 1];
+
+  for (idx, (digits, sign)) in coefficients.iter().enumerate() {
+    if sign.is_negative() {
+      panic!("Toom-Cook interpolation produced a negative coefficient");
+    }
+    digital_add_at_offset_u32(&mut result, digits, idx * part);
+  }
+
+  digital_trim_zeroes(&mut result);
+  result
+}
+
+#[inline]
+fn signed_from_digits(mut digits: Vec<u32>, sign: Sign) -> SignedDigits {
+  digital_trim_zeroes(&mut digits);
+
+  if digits == [0] {
+    (digits, Sign::Zero)
+  } else {
+    (digits, sign)
+  }
+}
+
+#[inline]
+fn signed_add(lhs: &SignedDigits, rhs: &SignedDigits) -> SignedDigits {
+  match (lhs.1, rhs.1) {
+    (_, Sign::Zero) => lhs.clone(),
+    (Sign::Zero, _) => rhs.clone(),
+    (left_sign, right_sign) if left_sign == right_sign => {
+      let mut digits = lhs.0.clone();
+      digital_add_at_offset_u32(&mut digits, &rhs.0, 0);
+      (digits, left_sign)
+    }
+    (left_sign, right_sign) => match digital_cmp(&lhs.0, &rhs.0) {
+      Ordering::Equal => (vec![0], Sign::Zero),
+      Ordering::Greater => {
+        let mut digits = lhs.0.clone();
+        digital_subtract_in_place_u32(&mut digits, &rhs.0);
+        (digits, left_sign)
+      }
+      Ordering::Less => {
+        let mut digits = rhs.0.clone();
+        digital_subtract_in_place_u32(&mut digits, &lhs.0);
+        (digits, right_sign)
+      }
+    },
+  }
+}
+
+#[inline(always)]
+fn signed_subtract(lhs: &SignedDigits, rhs: &SignedDigits) -> SignedDigits {
+  signed_add(lhs, &(rhs.0.clone(), rhs.1.negated()))
+}
+
+#[inline(always)]
+fn signed_shift_left(value: &SignedDigits, bits: usize) -> SignedDigits {
+  (digital_shift_left_u32(&value.0, bits), value.1)
+}
+
+#[inline(always)]
+fn signed_scalar_multiply(value: &SignedDigits, scalar: u32) -> SignedDigits {
+  let mut digits = value.0.clone();
+  digital_scalar_multiply_in_place_u32(&mut digits, scalar);
+  (digits, value.1)
+}
+
+#[inline(always)]
+fn signed_divide_exact(value: &SignedDigits, divisor: u32) -> SignedDigits {
+  let mut digits = value.0.clone();
+  digital_scalar_divide_in_place_u32(&mut digits, divisor);
+  signed_from_digits(digits, value.1)
+}
+
+#[inline]
+fn signed_multiply(
+  lhs: &SignedDigits,
+  rhs: &SignedDigits,
+  crossovers: MultiplyCrossovers,
+) -> SignedDigits {
+  if lhs.1.is_zero() || rhs.1.is_zero() {
+    return (vec![0], Sign::Zero);
+  }
+
+  let sign = if lhs.1 == rhs.1 {
+    Sign::Positive
+  } else {
+    Sign::Negative
+  };
+
+  let digits = if lhs.0 == rhs.0 {
+    digital_square_dispatch_u32(&lhs.0, crossovers)
+  } else {
+    digital_multiply_dispatch_u32(&lhs.0, &rhs.0, crossovers)
+  };
+
+  signed_from_digits(digits, sign)
+}
@@ -10,8 +10,7 @@ use super::{fit_shift, higher_order_multiply};
 #[inline(always)]
 pub fn digital_multiply_u32(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
   match (lhs.len(), rhs.len()) {
-    (0, _) => rhs.to_owned(),
-    (_, 0) => lhs.to_owned(),
+    (0, _) | (_, 0) => vec![0],
     (1, 1) => {
       let high_res = higher_order_multiply(lhs[0], rhs[0]);
       if high_res > u32::MAX as u64 {
@@ -0,0 +1,62 @@
+use super::digital_trim_zeroes;
+
+#[inline(always)]
+pub fn digital_shift_left_u32(lhs: &[u32], bits: usize) -> Vec<u32> {
+  let limb_shift = bits / 32;
+  let bit_shift = (bits % 32) as u32;
+
+  let mut result = vec![0; limb_shift];
+  result.reserve(lhs.len() + 1);
+
+  if bit_shift == 0 {
+    result.extend_from_slice(lhs);
+  } else {
+    let mut carry = 0;
+    for digit in lhs {
+      result.push((digit << bit_shift) | carry);
+      carry = digit >> (32 - bit_shift);
+    }
+    result.push(carry);
+  }
+
+  digital_trim_zeroes(&mut result);
+  result
+}
+
+#[inline(always)]
+pub fn digital_shift_right_u32(lhs: &[u32], bits: usize) -> Vec<u32> {
+  let limb_shift = bits / 32;
+  let bit_shift = (bits % 32) as u32;
+
+  if limb_shift >= lhs.len() {
+    return vec![0];
+  }
+
+  let shifted = &lhs[limb_shift..];
+  let mut result = Vec::with_capacity(shifted.len());
+
+  if bit_shift == 0 {
+    result.extend_from_slice(shifted);
+  } else {
+    for (idx, digit) in shifted.iter().enumerate() {
+      let high = shifted.get(idx + 1).copied().unwrap_or(0);
+      result.push((digit >> bit_shift) | (high << (32 - bit_shift)));
+    }
+  }
+
+  digital_trim_zeroes(&mut result);
+  result
+}
+
+#[inline(always)]
+pub fn digital_bit_length_u32(lhs: &[u32]) -> usize {
+  let mut magnitude = lhs.len();
+  while magnitude > 0 && lhs[magnitude - 1] == 0 {
+    magnitude -= 1;
+  }
+
+  match magnitude {
+    0 => 0,
+    _ => magnitude * 32 - lhs[magnitude - 1].leading_zeros() as usize,
+  }
+}
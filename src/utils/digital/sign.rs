@@ -37,21 +37,21 @@ impl Sign {
 impl PartialOrd for Sign {
   #[inline(always)]
   fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-    match (self, other) {
-      (Self::Positive, Self::Zero) => Some(Ordering::Greater),
-      (Self::Positive, Self::Negative) => Some(Ordering::Greater),
-      (Self::Zero, Self::Negative) => Some(Ordering::Greater),
-      (Self::Zero, Self::Positive) => Some(Ordering::Less),
-      (Self::Negative, Self::Zero) => Some(Ordering::Less),
-      (Self::Negative, Self::Positive) => Some(Ordering::Greater),
-      _ => Some(Ordering::Equal),
-    }
+    Some(self.cmp(other))
   }
 }
 
 impl Ord for Sign {
   #[inline(always)]
   fn cmp(&self, other: &Self) -> Ordering {
-    self.partial_cmp(other).unwrap()
+    match (self, other) {
+      (Self::Positive, Self::Zero) => Ordering::Greater,
+      (Self::Positive, Self::Negative) => Ordering::Greater,
+      (Self::Zero, Self::Negative) => Ordering::Greater,
+      (Self::Zero, Self::Positive) => Ordering::Less,
+      (Self::Negative, Self::Zero) => Ordering::Less,
+      (Self::Negative, Self::Positive) => Ordering::Less,
+      _ => Ordering::Equal,
+    }
   }
 }
@@ -25,7 +25,7 @@ where
     (true, true) => (result, Sign::Zero),
     (true, _) => {
       result.copy_from_slice(rhs);
-      (result, Sign::Positive)
+      (result, Sign::Negative)
     }
     (_, true) => {
       result.copy_from_slice(lhs);
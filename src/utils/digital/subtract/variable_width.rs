@@ -4,7 +4,7 @@ use std::{cmp::Ordering, fmt::Debug};
 use super::{digital_iterator_subtract_into_container, ignore_leading_zeroes};
 use crate::{
   traits::from_bool::FromBool,
-  utils::{digital_cmp, DigitalWrap, Sign},
+  utils::{digital_cmp, digital_trim_zeroes, DigitalWrap, Sign},
 };
 
 #[inline(always)]
@@ -20,7 +20,7 @@ where
   let right_zero = rhs.iter().all(|v| v.is_zero());
   match (left_zero, right_zero) {
     (true, true) => (vec![I::zero()], Sign::Zero),
-    (true, _) => (rhs.to_vec(), Sign::Positive),
+    (true, _) => (rhs.to_vec(), Sign::Negative),
     (_, true) => (lhs.to_vec(), Sign::Positive),
     _ => {
       let mut larger = lhs;
@@ -40,7 +40,7 @@ where
       let mut trimmed_larger = ignore_leading_zeroes(larger);
       let mut trimmed_smaller = ignore_leading_zeroes(smaller);
 
-      let mut result = vec![];
+      let mut result = vec![I::zero(); trimmed_larger.len()];
 
       digital_iterator_subtract_into_container(
         &mut trimmed_larger,
@@ -48,7 +48,44 @@ where
         &mut result,
         base,
       );
+      digital_trim_zeroes(&mut result);
       (result, sign)
     }
   }
 }
+
+#[inline(always)]
+pub fn digital_subtract_in_place_u32(lhs: &mut Vec<u32>, rhs: &[u32]) {
+  let mut right_magnitude = rhs.len();
+  while right_magnitude > 0 && rhs[right_magnitude - 1] == 0 {
+    right_magnitude -= 1;
+  }
+
+  if right_magnitude > lhs.len() {
+    panic!("Attempt to subtract with overflow");
+  }
+
+  let mut borrow = false;
+  for (idx, digit) in rhs.iter().take(right_magnitude).enumerate() {
+    let (before_borrow, first_borrow) = lhs[idx].overflowing_sub(*digit);
+    let (after_borrow, second_borrow) =
+      before_borrow.overflowing_sub(borrow as u32);
+
+    lhs[idx] = after_borrow;
+    borrow = first_borrow || second_borrow;
+  }
+
+  let mut current_idx = right_magnitude;
+  while borrow {
+    if current_idx == lhs.len() {
+      panic!("Attempt to subtract with overflow");
+    }
+
+    let (after_borrow, next_borrow) = lhs[current_idx].overflowing_sub(1);
+    lhs[current_idx] = after_borrow;
+    borrow = next_borrow;
+    current_idx += 1;
+  }
+
+  digital_trim_zeroes(lhs);
+}
@@ -0,0 +1,15 @@
+use num::Integer;
+
+#[inline(always)]
+pub fn digital_trim_zeroes<I>(digits: &mut Vec<I>)
+where
+  I: Integer + Copy,
+{
+  while digits.len() > 1 && digits[digits.len() - 1].is_zero() {
+    digits.pop();
+  }
+
+  if digits.is_empty() {
+    digits.push(I::zero());
+  }
+}
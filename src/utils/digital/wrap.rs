@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
 use num::{Bounded, FromPrimitive, Integer, Unsigned};
 
@@ -14,16 +14,16 @@ pub enum DigitalWrap {
   U8(u8),
 }
 
-impl ToString for DigitalWrap {
-  fn to_string(&self) -> String {
+impl Display for DigitalWrap {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
     match self {
-      DigitalWrap::Max => "Maximum Value".to_string(),
-      DigitalWrap::Ten => "10".to_string(),
-      DigitalWrap::U128(val) => format!("{val}u128"),
-      DigitalWrap::U64(val) => format!("{val}u64"),
-      DigitalWrap::U32(val) => format!("{val}u32"),
-      DigitalWrap::U16(val) => format!("{val}u16"),
-      DigitalWrap::U8(val) => format!("{val}u8"),
+      DigitalWrap::Max => write!(formatter, "Maximum Value"),
+      DigitalWrap::Ten => write!(formatter, "10"),
+      DigitalWrap::U128(val) => write!(formatter, "{val}u128"),
+      DigitalWrap::U64(val) => write!(formatter, "{val}u64"),
+      DigitalWrap::U32(val) => write!(formatter, "{val}u32"),
+      DigitalWrap::U16(val) => write!(formatter, "{val}u16"),
+      DigitalWrap::U8(val) => write!(formatter, "{val}u8"),
     }
   }
 }
@@ -1,3 +1,5 @@
 mod digital;
+mod random;
 
 pub use digital::*;
+pub use random::SplitMix64;
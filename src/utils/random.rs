@@ -0,0 +1,26 @@
+/// SplitMix64, a small seedable generator for choosing witnesses and curve
+/// parameters reproducibly. Not suitable for anything cryptographic.
+#[derive(Debug, Clone)]
+pub struct SplitMix64(u64);
+
+impl SplitMix64 {
+  #[inline(always)]
+  pub fn new(seed: u64) -> Self {
+    Self(seed)
+  }
+
+  #[inline(always)]
+  pub fn next_u64(&mut self) -> u64 {
+    self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+
+    let mut mixed = self.0;
+    mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94d049bb133111eb);
+    mixed ^ (mixed >> 31)
+  }
+
+  #[inline(always)]
+  pub fn next_u32(&mut self) -> u32 {
+    (self.next_u64() >> 32) as u32
+  }
+}